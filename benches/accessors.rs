@@ -0,0 +1,43 @@
+//! Benchmarks showing that accessing refined values does not depend on the cost of predicates.
+
+use std::hint::black_box;
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use refinement_types::{Refinement, str::Ascii};
+
+type AsciiString = Refinement<String, Ascii>;
+
+const LENGTHS: [usize; 4] = [16, 256, 4096, 65536];
+
+fn accessors(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("accessors");
+
+    for length in LENGTHS {
+        let string = "a".repeat(length);
+
+        group.bench_with_input(
+            BenchmarkId::new("refine", length),
+            &string,
+            |bencher, string| {
+                bencher.iter_batched(
+                    || string.clone(),
+                    AsciiString::refine,
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+
+        let refined = AsciiString::refine(string).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("get", length),
+            &refined,
+            |bencher, refined| bencher.iter(|| black_box(refined).get().len()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, accessors);
+criterion_main!(benches);
@@ -0,0 +1,109 @@
+//! Deriving `HasLength`.
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{Data, DeriveInput, Error, Fields, Ident, Index, Result, parse_quote};
+
+/// The `length` attribute name.
+const LENGTH: &str = "length";
+
+/// The `method` attribute argument name.
+const METHOD: &str = "method";
+
+fn method(input: &DeriveInput) -> Result<Option<Ident>> {
+    let mut method = None;
+
+    for attribute in &input.attrs {
+        if !attribute.path().is_ident(LENGTH) {
+            continue;
+        }
+
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident(METHOD) {
+                method = Some(meta.value()?.parse()?);
+
+                Ok(())
+            } else {
+                Err(meta.error("expected `method = name`"))
+            }
+        })?;
+    }
+
+    Ok(method)
+}
+
+pub fn derive(mut input: DeriveInput) -> Result<TokenStream> {
+    let has_length = quote!(::refinement_types::length::HasLength);
+
+    let body = if let Some(method) = method(&input)? {
+        quote!(self.#method())
+    } else {
+        let Data::Struct(data) = &input.data else {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "expected struct or `#[length(method = name)]`",
+            ));
+        };
+
+        let fields: Vec<_> = match &data.fields {
+            Fields::Named(named) => named.named.iter().collect(),
+            Fields::Unnamed(unnamed) => unnamed.unnamed.iter().collect(),
+            Fields::Unit => Vec::new(),
+        };
+
+        let marked: Vec<_> = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| {
+                field
+                    .attrs
+                    .iter()
+                    .any(|attribute| attribute.path().is_ident(LENGTH))
+            })
+            .collect();
+
+        let (index, field) = match (fields.as_slice(), marked.as_slice()) {
+            ([field], []) => (0, *field),
+            (_, [(index, field)]) => (*index, **field),
+            (_, []) => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "expected single field or field marked with `#[length]`",
+                ));
+            }
+            (_, [_, (_, field), ..]) => {
+                return Err(Error::new_spanned(
+                    field,
+                    "expected only one field marked with `#[length]`",
+                ));
+            }
+        };
+
+        let member = field.ident.as_ref().map_or_else(
+            || Index::from(index).into_token_stream(),
+            ToTokens::into_token_stream,
+        );
+
+        let field_type = field.ty.clone();
+
+        input
+            .generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#field_type: #has_length));
+
+        quote!(#has_length::length(&self.#member))
+    };
+
+    let name = &input.ident;
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #has_length for #name #type_generics #where_clause {
+            fn length(&self) -> usize {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,132 @@
+//! Derive macros for refinement types.
+//!
+//! This crate is not intended to be used directly; instead, enable the `derive` feature
+//! of the `refinement-types` crate, which re-exports the macros defined here.
+
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, ItemFn, meta, parse_macro_input};
+
+mod length;
+mod predicate;
+
+/// Derives `HasLength` for structs.
+///
+/// The length is taken from the only field of the struct, or from the field marked
+/// with `#[length]` if there are multiple fields.
+///
+/// Alternatively, `#[length(method = name)]` on the struct itself makes the length
+/// be returned by calling `self.name()`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Refinement, length::{self, HasLength}};
+///
+/// #[derive(HasLength)]
+/// struct Buffer(Vec<u8>);
+///
+/// let buffer = Buffer(vec![13, 42]);
+///
+/// assert_eq!(buffer.length(), 2);
+///
+/// assert!(Refinement::<Buffer, length::Equal<2>>::refine(buffer).is_ok());
+/// ```
+///
+/// Selecting the field:
+///
+/// ```
+/// use refinement_types::length::HasLength;
+///
+/// #[derive(HasLength)]
+/// struct Named {
+///     id: u64,
+///     #[length]
+///     data: String,
+/// }
+///
+/// let named = Named { id: 0, data: "nekit".to_owned() };
+///
+/// assert_eq!(named.length(), 5);
+/// ```
+///
+/// Using the method:
+///
+/// ```
+/// use refinement_types::length::HasLength;
+///
+/// #[derive(HasLength)]
+/// #[length(method = count)]
+/// struct Pair {
+///     left: Option<u8>,
+///     right: Option<u8>,
+/// }
+///
+/// impl Pair {
+///     fn count(&self) -> usize {
+///         usize::from(self.left.is_some()) + usize::from(self.right.is_some())
+///     }
+/// }
+///
+/// let pair = Pair { left: Some(13), right: None };
+///
+/// assert_eq!(pair.length(), 1);
+/// ```
+#[proc_macro_derive(HasLength, attributes(length))]
+pub fn derive_has_length(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    length::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives predicates from functions.
+///
+/// Given some `fn name(value: &T) -> bool`, this attribute generates the predicate type
+/// `Name` implementing `Predicate<T>`, along with the `NameError` error type.
+///
+/// # Arguments
+///
+/// - `expected` (required) is the expectation of the predicate;
+/// - `code` (required) is the expectation code of the predicate;
+/// - `message` (optional) is the error message, defaults to `expected {expected}`;
+/// - `name` (optional) is the name of the predicate type, defaults to the function name
+///   converted to `PascalCase`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Refinement, predicate};
+///
+/// #[predicate(expected = "even number", code = "u32::even")]
+/// fn is_even(value: &u32) -> bool {
+///     value % 2 == 0
+/// }
+///
+/// type Even = Refinement<u32, IsEven>;
+///
+/// let even = Even::refine(42).unwrap();
+///
+/// assert_eq!(*even, 42);
+///
+/// let error = Even::refine(13).unwrap_err();
+///
+/// assert_eq!(error.error.to_string(), "expected even number");
+/// assert_eq!(error.to_string(), "expected even number (code `u32::even`) [no context]");
+/// ```
+#[proc_macro_attribute]
+pub fn predicate(arguments: TokenStream, input: TokenStream) -> TokenStream {
+    let mut parsed = predicate::Arguments::default();
+
+    let parser = meta::parser(|meta| parsed.parse(&meta));
+
+    parse_macro_input!(arguments with parser);
+
+    let function = parse_macro_input!(input as ItemFn);
+
+    predicate::derive(parsed, function)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,181 @@
+//! Deriving predicates from functions.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    Error, FnArg, Ident, ItemFn, LitStr, Result, Type, meta::ParseNestedMeta, spanned::Spanned,
+};
+
+/// The `name` argument name.
+const NAME: &str = "name";
+
+/// The `expected` argument name.
+const EXPECTED: &str = "expected";
+
+/// The `code` argument name.
+const CODE: &str = "code";
+
+/// The `message` argument name.
+const MESSAGE: &str = "message";
+
+/// The `doc` attribute name.
+const DOC: &str = "doc";
+
+/// Arguments of the `predicate` attribute.
+#[derive(Default)]
+pub struct Arguments {
+    name: Option<Ident>,
+    expected: Option<LitStr>,
+    code: Option<LitStr>,
+    message: Option<LitStr>,
+}
+
+impl Arguments {
+    pub fn parse(&mut self, meta: &ParseNestedMeta<'_>) -> Result<()> {
+        if meta.path.is_ident(NAME) {
+            self.name = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident(EXPECTED) {
+            self.expected = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident(CODE) {
+            self.code = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident(MESSAGE) {
+            self.message = Some(meta.value()?.parse()?);
+        } else {
+            return Err(meta.error("expected `name`, `expected`, `code` or `message`"));
+        }
+
+        Ok(())
+    }
+}
+
+fn pascal_case(name: &Ident) -> Ident {
+    let string = name.to_string();
+
+    let converted: String = string
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut characters = part.chars();
+
+            characters.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(characters).collect()
+            })
+        })
+        .collect();
+
+    Ident::new(&converted, name.span())
+}
+
+pub fn derive(arguments: Arguments, function: ItemFn) -> Result<TokenStream> {
+    let function_name = &function.sig.ident;
+
+    let Some(expected) = arguments.expected else {
+        return Err(Error::new_spanned(
+            function_name,
+            "expected `expected = \"...\"` argument",
+        ));
+    };
+
+    let Some(code) = arguments.code else {
+        return Err(Error::new_spanned(
+            function_name,
+            "expected `code = \"...\"` argument",
+        ));
+    };
+
+    let message = arguments.message.unwrap_or_else(|| {
+        LitStr::new(
+            &format!("expected {value}", value = expected.value()),
+            expected.span(),
+        )
+    });
+
+    let inputs = &function.sig.inputs;
+
+    let value_type = match inputs.first() {
+        Some(FnArg::Typed(typed)) if inputs.len() == 1 => match typed.ty.as_ref() {
+            Type::Reference(reference) if reference.mutability.is_none() => &reference.elem,
+            other => return Err(Error::new(other.span(), "expected shared reference")),
+        },
+        _ => {
+            return Err(Error::new(
+                inputs.span(),
+                "expected single argument of shared reference type",
+            ));
+        }
+    };
+
+    let name = arguments.name.unwrap_or_else(|| pascal_case(function_name));
+
+    let error = format_ident!("{name}Error");
+
+    let visibility = &function.vis;
+
+    let documentation: Vec<_> = function
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident(DOC))
+        .collect();
+
+    let predicate_documentation = if documentation.is_empty() {
+        let string = format!("Predicate derived from [`{function_name}`].");
+
+        quote!(#[doc = #string])
+    } else {
+        quote!(#(#documentation)*)
+    };
+
+    let error_documentation = format!("Represents errors returned by [`{name}`].");
+
+    let predicate = quote!(::refinement_types::core::Predicate);
+
+    let fmt = quote!(::core::fmt);
+
+    Ok(quote! {
+        #function
+
+        #predicate_documentation
+        #visibility struct #name {
+            private: ::core::marker::PhantomData<()>,
+        }
+
+        #[doc = #error_documentation]
+        #[derive(Debug, Default)]
+        #visibility struct #error;
+
+        impl #error {
+            /// Constructs [`Self`].
+            #visibility const fn new() -> Self {
+                Self
+            }
+        }
+
+        impl #fmt::Display for #error {
+            fn fmt(&self, formatter: &mut #fmt::Formatter<'_>) -> #fmt::Result {
+                formatter.write_str(#message)
+            }
+        }
+
+        impl ::core::error::Error for #error {}
+
+        impl #predicate<#value_type> for #name {
+            type Error = #error;
+
+            fn check(value: &#value_type) -> ::core::result::Result<(), Self::Error> {
+                if #function_name(value) {
+                    Ok(())
+                } else {
+                    Err(Self::Error::new())
+                }
+            }
+
+            fn expect(formatter: &mut #fmt::Formatter<'_>) -> #fmt::Result {
+                formatter.write_str(#expected)
+            }
+
+            fn expect_code(formatter: &mut #fmt::Formatter<'_>) -> #fmt::Result {
+                formatter.write_str(#code)
+            }
+        }
+    })
+}
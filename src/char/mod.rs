@@ -1,6 +1,8 @@
 //! Predicates for characters.
 
 pub mod ascii;
+#[cfg(feature = "unicode-script")]
+pub mod script;
 pub mod unicode;
 
 pub use unicode::{
@@ -0,0 +1,148 @@
+//! Predicates based on Unicode scripts.
+//!
+//! Scripts are determined by the Unicode `Script` property (not `Script_Extensions`),
+//! meaning that characters shared between scripts, like spaces, digits and punctuation,
+//! are in the `Common` script, and are not in any of the scripts provided here.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use unicode_script::UnicodeScript as _;
+
+use crate::{core::Predicate, static_str::StaticStr};
+
+pub use unicode_script::Script as UnicodeScript;
+
+/// Represents Unicode scripts.
+pub trait Script {
+    /// The name of the script.
+    const NAME: StaticStr;
+
+    /// The value of the `Script` property.
+    const SCRIPT: UnicodeScript;
+
+    /// Checks whether the given character is in the script.
+    fn contains(character: char) -> bool {
+        character.script() == Self::SCRIPT
+    }
+}
+
+macro_rules! scripts {
+    ($($name: ident),+ $(,)?) => {
+        $(
+            #[doc = concat!("Represents the `", stringify!($name), "` script.")]
+            pub struct $name {
+                private: PhantomData<()>,
+            }
+
+            impl Script for $name {
+                const NAME: StaticStr = stringify!($name);
+
+                const SCRIPT: UnicodeScript = UnicodeScript::$name;
+            }
+        )+
+    };
+}
+
+scripts! {
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Thai,
+    Hangul,
+    Hiragana,
+    Katakana,
+    Han,
+}
+
+/// Represents errors that occur when the character is not in the script.
+#[derive(Debug, Error)]
+#[error("received character {character:?} not in `{script}` script")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(char::script),
+        help("make sure the character is in `{script}` script")
+    )
+)]
+pub struct InScriptError {
+    /// The received character.
+    pub character: char,
+    /// The name of the script.
+    pub script: StaticStr,
+}
+
+impl InScriptError {
+    /// Constructs [`Self`].
+    pub const fn new(character: char, script: StaticStr) -> Self {
+        Self { character, script }
+    }
+}
+
+/// Checks whether the given character is in the script `S`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     char::script::{Cyrillic, Greek, InScript, Latin},
+/// };
+///
+/// assert!(InScript::<Latin>::is_satisfied(&'é'));
+/// assert!(InScript::<Cyrillic>::is_satisfied(&'ж'));
+/// assert!(InScript::<Greek>::is_satisfied(&'λ'));
+///
+/// let error = InScript::<Latin>::check(&'ж').unwrap_err();
+///
+/// assert_eq!(error.script, "Latin");
+///
+/// // coptic letters share the block with greek ones, but not the script
+/// assert!(!InScript::<Greek>::is_satisfied(&'Ϣ'));
+///
+/// // digits are in the common script
+/// assert!(!InScript::<Latin>::is_satisfied(&'7'));
+/// ```
+pub struct InScript<S: Script + ?Sized> {
+    script: PhantomData<S>,
+}
+
+impl<S: Script + ?Sized> InScript<S> {
+    /// Returns the name of the script.
+    pub const fn script() -> StaticStr {
+        S::NAME
+    }
+}
+
+impl<S: Script + ?Sized> Predicate<char> for InScript<S> {
+    type Error = InScriptError;
+
+    fn check(value: &char) -> Result<(), Self::Error> {
+        if S::contains(*value) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(*value, Self::script()))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "character in `{script}` script",
+            script = Self::script()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "char::script<{script}>", script = Self::script())
+    }
+}
@@ -5,7 +5,9 @@ use core::{
     fmt,
     hash::{Hash, Hasher},
     marker::PhantomData,
+    mem,
     ops::Deref,
+    panic::Location,
 };
 
 #[cfg(feature = "unsafe-assert")]
@@ -21,8 +23,50 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
 
 use crate::{static_str::StaticStr, type_str, type_str::TypeStr};
 
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+#[cfg(feature = "derive")]
+pub use refinement_types_derive::predicate;
+
 type_str!(pub NoContext = "no context" => "Represents the abscence of context.");
 
+/// Represents contexts equivalent to the context `D`.
+///
+/// Every context is equivalent to itself; other equivalences are declared
+/// via the [`context_eq!`] macro, which ensures that the values are equal.
+///
+/// [`context_eq!`]: crate::context_eq
+pub trait ContextEq<D: TypeStr + ?Sized>: TypeStr {}
+
+impl<C: TypeStr + ?Sized> ContextEq<C> for C {}
+
+/// Refines the given value, capturing the call site location in case of errors.
+///
+/// The location is stored in [`struct@Error`] and included in its display,
+/// so logs show where the invalid value originated.
+///
+/// This macro is only available with the `track-caller` feature.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Refinement, int::u8, refine_located};
+///
+/// let result: Result<Refinement<u8, u8::Less<100>>, _> = refine_located!(142);
+///
+/// let location = result.unwrap_err().location().unwrap();
+///
+/// assert_eq!(location.line(), line!() - 4);
+/// ```
+#[cfg(feature = "track-caller")]
+#[macro_export]
+macro_rules! refine_located {
+    ($value: expr) => {
+        $crate::core::Refinement::refine_located($value)
+    };
+}
+
 /// Literal `expected` string.
 pub const EXPECTED: StaticStr = "expected";
 
@@ -71,6 +115,18 @@ pub trait Predicate<T: ?Sized> {
     fn expected_code() -> ExpectedCode<T, Self> {
         ExpectedCode::new()
     }
+
+    /// Returns the expectation of the predicate as [`String`].
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn expected_string() -> String {
+        Self::expected().to_string()
+    }
+
+    /// Returns the expectation code of the predicate as [`String`].
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    fn expected_code_string() -> String {
+        Self::expected_code().to_string()
+    }
 }
 
 /// Represents expectations of predicates.
@@ -119,6 +175,23 @@ impl<T: ?Sized, P: Predicate<T> + ?Sized> fmt::Display for ExpectedCode<T, P> {
 ///
 /// Values of this type are guaranteed to contain values of type `T`
 /// that satisfy the predicate `P`.
+///
+/// # Performance
+///
+/// The predicate is checked exactly once, when the refinement is constructed
+/// (for instance, via [`refine`]). Accessing the value via [`get`], [`take`]
+/// or dereferencing never re-runs the check, thus it is free regardless of
+/// the cost of the predicate.
+///
+/// The only exception is the `unsafe-assert` feature, which evaluates [`is_fine`]
+/// on every access in order to pass the result to [`assert_unchecked`], allowing the compiler
+/// to optimize based on the invariant. This feature is therefore best used with cheap predicates.
+///
+/// [`refine`]: Self::refine
+/// [`get`]: Self::get
+/// [`take`]: Self::take
+/// [`is_fine`]: Self::is_fine
+/// [`assert_unchecked`]: core::hint::assert_unchecked
 pub struct Refinement<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized = NoContext> {
     value: T,
     predicate: PhantomData<P>,
@@ -265,6 +338,12 @@ pub struct Error<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized = NoContext> {
 
     /// The context of the refinement.
     pub context: PhantomData<C>,
+
+    /// The location where the refinement was attempted, if captured.
+    ///
+    /// This field is private and present regardless of the `track-caller` feature,
+    /// so that enabling the feature anywhere does not change the fields of this type.
+    location: Option<&'static Location<'static>>,
 }
 
 impl<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> fmt::Display for Error<T, P, C> {
@@ -275,7 +354,13 @@ impl<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> fmt::Display for Error<T,
             expected = P::expected(),
             code = P::expected_code(),
             context = Self::context(),
-        )
+        )?;
+
+        if let Some(location) = self.location() {
+            write!(formatter, " at {location}")?;
+        }
+
+        Ok(())
     }
 }
 
@@ -319,6 +404,7 @@ impl<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> Error<T, P, C> {
             value,
             error,
             context: PhantomData,
+            location: None,
         }
     }
 
@@ -336,6 +422,19 @@ impl<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> Error<T, P, C> {
     pub const fn context() -> StaticStr {
         C::VALUE
     }
+
+    /// Returns the location where the refinement was attempted, if captured.
+    pub const fn location(&self) -> Option<&'static Location<'static>> {
+        self.location
+    }
+
+    /// Attaches the given location to the error.
+    #[must_use]
+    pub const fn with_location(mut self, location: &'static Location<'static>) -> Self {
+        self.location = Some(location);
+
+        self
+    }
 }
 
 impl<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> Error<T, P, C> {
@@ -371,6 +470,23 @@ impl<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> Refinement<T, P, C> {
         }
     }
 
+    /// Refines the given value, capturing the caller location in case of errors.
+    ///
+    /// See [`refine_located!`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the value does not satisfy the predicate.
+    ///
+    /// [`refine_located!`]: crate::refine_located
+    #[cfg(feature = "track-caller")]
+    #[track_caller]
+    pub fn refine_located(value: T) -> Result<Self, Error<T, P, C>> {
+        let location = Location::caller();
+
+        Self::refine(value).map_err(|error| error.with_location(location))
+    }
+
     /// Checks the given value.
     ///
     /// This is the same as calling [`P::check`] on the value, where `P` is the predicate type.
@@ -412,6 +528,44 @@ impl<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> Refinement<T, P, C> {
         unsafe { Self::unchecked(function(self.take())) }
     }
 
+    /// Maps the value of the refinement, changing both the predicate and the context.
+    ///
+    /// This is useful for re-tagging values as they move between domains.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the resulting value does not satisfy the new predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{Refinement, int::u32, type_str};
+    ///
+    /// type_str!(Seconds = "seconds");
+    /// type_str!(Milliseconds = "milliseconds");
+    ///
+    /// let seconds = Refinement::<u32, u32::LessOrEqual<60>, Seconds>::refine(30).unwrap();
+    ///
+    /// let milliseconds: Refinement<u32, u32::LessOrEqual<60_000>, Milliseconds> =
+    ///     seconds.remap(|value| value * 1000).unwrap();
+    ///
+    /// assert_eq!(*milliseconds, 30_000);
+    ///
+    /// let seconds = Refinement::<u32, u32::LessOrEqual<60>, Seconds>::refine(30).unwrap();
+    ///
+    /// let error = seconds
+    ///     .remap::<u32::LessOrEqual<1000>, Milliseconds, _>(|value| value * 1000)
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(*error.value(), 30_000);
+    /// ```
+    pub fn remap<Q: Predicate<T> + ?Sized, D: TypeStr + ?Sized, F: FnOnce(T) -> T>(
+        self,
+        function: F,
+    ) -> Result<Refinement<T, Q, D>, Error<T, Q, D>> {
+        Refinement::refine(function(self.take()))
+    }
+
     /// Replaces the value of the refinement.
     ///
     /// # Errors
@@ -421,6 +575,41 @@ impl<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> Refinement<T, P, C> {
         Self::refine(value)
     }
 
+    /// Replaces the value of the refinement in place, returning the old value.
+    ///
+    /// Unlike [`replace`], the refinement is left untouched if the new value
+    /// does not satisfy the predicate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the new value does not satisfy the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{Refinement, int::u8};
+    ///
+    /// type Small = Refinement<u8, u8::Less<100>>;
+    ///
+    /// let mut small = Small::refine(13).unwrap();
+    ///
+    /// assert_eq!(small.try_replace(42).unwrap(), 13);
+    /// assert_eq!(*small, 42);
+    ///
+    /// let error = small.try_replace(255).unwrap_err();
+    ///
+    /// assert_eq!(*error.value(), 255);
+    /// assert_eq!(*small, 42);
+    /// ```
+    ///
+    /// [`replace`]: Self::replace
+    pub fn try_replace(&mut self, value: T) -> Result<T, Error<T, P, C>> {
+        match Self::check(&value) {
+            Ok(()) => Ok(mem::replace(&mut self.value, value)),
+            Err(error) => Err(Error::new(value, error)),
+        }
+    }
+
     /// Replaces the value of the refinement without checking the new value.
     ///
     /// # Safety
@@ -431,6 +620,57 @@ impl<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> Refinement<T, P, C> {
         unsafe { Self::unchecked(value) }
     }
 
+    /// Swaps the values of two refinements.
+    ///
+    /// No checks are needed, as both values already satisfy the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{Refinement, int::u8};
+    ///
+    /// type Small = Refinement<u8, u8::Less<100>>;
+    ///
+    /// let mut left = Small::refine(13).unwrap();
+    /// let mut right = Small::refine(42).unwrap();
+    ///
+    /// left.swap(&mut right);
+    ///
+    /// assert_eq!(*left, 42);
+    /// assert_eq!(*right, 13);
+    /// ```
+    pub const fn swap(&mut self, other: &mut Self) {
+        mem::swap(&mut self.value, &mut other.value);
+    }
+
+    /// Changes the context of the refinement to the equivalent context `D`.
+    ///
+    /// No checks are needed, as the context does not affect the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{Refinement, context_eq, int::u16, type_str};
+    ///
+    /// type_str!(Port = "port");
+    /// type_str!(OtherPort = "port");
+    ///
+    /// context_eq!(Port, OtherPort);
+    ///
+    /// let port = Refinement::<u16, u16::NonZero, Port>::refine(8080).unwrap();
+    ///
+    /// let other: Refinement<u16, u16::NonZero, OtherPort> = port.map_context();
+    ///
+    /// assert_eq!(*other, 8080);
+    /// ```
+    pub fn map_context<D: TypeStr + ?Sized>(self) -> Refinement<T, P, D>
+    where
+        C: ContextEq<D>,
+    {
+        // SAFETY: the value satisfies the predicate, regardless of the context
+        unsafe { Refinement::unchecked(self.take()) }
+    }
+
     #[cfg(feature = "unsafe-assert")]
     fn assert_refined(&self) {
         unsafe { assert_unchecked(Self::is_fine(&self.value)) }
@@ -452,4 +692,171 @@ impl<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> Refinement<T, P, C> {
 
         &self.value
     }
+
+    /// Returns the expectation and the expectation code of the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{Refinement, int::u8};
+    ///
+    /// type Digit = Refinement<u8, u8::ClosedOpen<0, 10>>;
+    ///
+    /// let (expected, code) = Digit::describe();
+    ///
+    /// assert_eq!(expected, "(u8 >= 0) and (u8 < 10)");
+    /// assert_eq!(code, "and<u8::ge<0>, u8::lt<10>>");
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub fn describe() -> (String, String) {
+        (P::expected_string(), P::expected_code_string())
+    }
+
+    /// Checks whether the values of two refinements are equal,
+    /// regardless of their predicates and contexts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{Refinement, int::i32};
+    ///
+    /// let positive = Refinement::<i32, i32::Positive>::refine(13).unwrap();
+    /// let non_negative = Refinement::<i32, i32::NonNegative>::refine(13).unwrap();
+    ///
+    /// assert!(positive.value_eq(&non_negative));
+    /// ```
+    pub fn value_eq<Q: Predicate<T> + ?Sized, D: TypeStr + ?Sized>(
+        &self,
+        other: &Refinement<T, Q, D>,
+    ) -> bool
+    where
+        T: PartialEq,
+    {
+        self.get() == other.get()
+    }
+}
+
+/// Represents errors that occur when converting refinements.
+pub enum ConvertError<E, U, Q: Predicate<U> + ?Sized, C: TypeStr + ?Sized = NoContext> {
+    /// The value could not be converted.
+    Convert(E),
+    /// The converted value does not satisfy the predicate.
+    Refine(Error<U, Q, C>),
+}
+
+impl<E: fmt::Display, U, Q: Predicate<U> + ?Sized, C: TypeStr + ?Sized> fmt::Display
+    for ConvertError<E, U, Q, C>
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Convert(error) => write!(formatter, "conversion failed: {error}"),
+            Self::Refine(error) => error.fmt(formatter),
+        }
+    }
+}
+
+impl<E: fmt::Debug, U, Q: Predicate<U> + ?Sized, C: TypeStr + ?Sized> fmt::Debug
+    for ConvertError<E, U, Q, C>
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Convert(error) => formatter.debug_tuple("Convert").field(error).finish(),
+            Self::Refine(error) => formatter.debug_tuple("Refine").field(error).finish(),
+        }
+    }
+}
+
+impl<E: ErrorCore + 'static, U, Q: Predicate<U> + ?Sized, C: TypeStr + ?Sized> ErrorCore
+    for ConvertError<E, U, Q, C>
+where
+    Q::Error: ErrorCore + 'static,
+{
+    fn source(&self) -> Option<&(dyn ErrorCore + 'static)> {
+        match self {
+            Self::Convert(error) => Some(error),
+            Self::Refine(error) => error.source(),
+        }
+    }
+}
+
+impl<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> Refinement<T, P, C> {
+    /// Converts the value of the refinement and refines it with the predicate `Q`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConvertError::Convert`] if the value could not be converted, and
+    /// [`ConvertError::Refine`] if the converted value does not satisfy the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{
+    ///     Refinement,
+    ///     core::ConvertError,
+    ///     int::{i32, i64},
+    /// };
+    ///
+    /// let small = Refinement::<i64, i64::Positive>::refine(42).unwrap();
+    ///
+    /// let converted = small.try_convert::<i32, i32::Positive>().unwrap();
+    ///
+    /// assert_eq!(*converted, 42);
+    ///
+    /// let large = Refinement::<i64, i64::Positive>::refine(i64::MAX).unwrap();
+    ///
+    /// let error = large.try_convert::<i32, i32::Positive>().unwrap_err();
+    ///
+    /// assert!(matches!(error, ConvertError::Convert(_)));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn try_convert<U, Q: Predicate<U> + ?Sized>(
+        self,
+    ) -> Result<Refinement<U, Q, C>, ConvertError<T::Error, U, Q, C>>
+    where
+        T: TryInto<U>,
+    {
+        let value = self.take().try_into().map_err(ConvertError::Convert)?;
+
+        Refinement::refine(value).map_err(ConvertError::Refine)
+    }
+}
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Partitions the given values into refinements and errors, in one pass.
+///
+/// The order of values is preserved within both partitions.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Refinement, core::partition_refined, int::u8};
+///
+/// type Small = Refinement<u8, u8::Less<100>>;
+///
+/// let (small, errors): (Vec<Small>, _) = partition_refined(vec![13, 255, 42, 100]);
+///
+/// let small: Vec<_> = small.into_iter().map(Small::take).collect();
+/// let rejected: Vec<_> = errors.iter().map(|error| *error.value()).collect();
+///
+/// assert_eq!(small, [13, 42]);
+/// assert_eq!(rejected, [255, 100]);
+/// ```
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[allow(clippy::type_complexity)]
+pub fn partition_refined<T, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized>(
+    values: Vec<T>,
+) -> (Vec<Refinement<T, P, C>>, Vec<Error<T, P, C>>) {
+    let mut refined = Vec::new();
+    let mut errors = Vec::new();
+
+    for value in values {
+        match Refinement::refine(value) {
+            Ok(refinement) => refined.push(refinement),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    (refined, errors)
 }
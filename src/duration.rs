@@ -0,0 +1,219 @@
+//! Predicates based on durations.
+
+use core::{fmt, marker::PhantomData, time::Duration};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::core::Predicate;
+
+/// Represents type-level durations.
+pub trait TypeDuration {
+    /// The duration value.
+    const VALUE: Duration;
+}
+
+#[doc(hidden)]
+pub mod import {
+    pub use core::{marker::PhantomData, time::Duration};
+}
+
+/// Lifts constant durations to type-level durations.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use refinement_types::type_duration;
+///
+/// type_duration!(Second = Duration::from_secs(1));
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```
+/// use core::{marker::PhantomData, time::Duration};
+///
+/// use refinement_types::TypeDuration;
+///
+/// struct Second {
+///     private: PhantomData<()>,
+/// }
+///
+/// impl TypeDuration for Second {
+///     const VALUE: Duration = Duration::from_secs(1);
+/// }
+/// ```
+#[macro_export]
+macro_rules! type_duration {
+    ($vis: vis $name: ident = $value: expr $(=> $doc: expr)?) => {
+        $(
+            #[doc = $doc]
+        )?
+        $vis struct $name {
+            private: $crate::duration::import::PhantomData<()>,
+        }
+
+        impl $crate::duration::TypeDuration for $name {
+            const VALUE: $crate::duration::import::Duration = $value;
+        }
+    };
+}
+
+/// Represents errors that occur when the duration is not between
+/// [`low`] and [`high`] (inclusive).
+///
+/// [`low`]: Self::low
+/// [`high`]: Self::high
+#[derive(Debug, Error)]
+#[error("received duration outside of [{low:?}, {high:?}]")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(duration::between),
+        help("make sure the duration is between {low:?} and {high:?}")
+    )
+)]
+pub struct DurationBetweenError {
+    /// The lower bound (the `L`).
+    pub low: Duration,
+    /// The upper bound (the `H`).
+    pub high: Duration,
+}
+
+impl DurationBetweenError {
+    /// Constructs [`Self`].
+    pub const fn new(low: Duration, high: Duration) -> Self {
+        Self { low, high }
+    }
+}
+
+/// Checks whether the given duration is within the `[L, H]` interval.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use refinement_types::{Predicate, duration::DurationBetween, type_duration};
+///
+/// type_duration!(Second = Duration::from_secs(1));
+/// type_duration!(Minute = Duration::from_secs(60));
+///
+/// type Backoff = DurationBetween<Second, Minute>;
+///
+/// assert!(Backoff::is_satisfied(&Duration::from_secs(5)));
+/// assert!(!Backoff::is_satisfied(&Duration::from_millis(500)));
+/// assert!(!Backoff::is_satisfied(&Duration::from_secs(61)));
+///
+/// assert_eq!(Backoff::expected_code().to_string(), "duration::between<1s, 60s>");
+/// ```
+pub struct DurationBetween<L: TypeDuration + ?Sized, H: TypeDuration + ?Sized> {
+    low: PhantomData<L>,
+    high: PhantomData<H>,
+}
+
+impl<L: TypeDuration + ?Sized, H: TypeDuration + ?Sized> DurationBetween<L, H> {
+    /// Returns the lower bound.
+    pub const fn low() -> Duration {
+        L::VALUE
+    }
+
+    /// Returns the upper bound.
+    pub const fn high() -> Duration {
+        H::VALUE
+    }
+}
+
+impl<L: TypeDuration + ?Sized, H: TypeDuration + ?Sized> Predicate<Duration>
+    for DurationBetween<L, H>
+{
+    type Error = DurationBetweenError;
+
+    fn check(value: &Duration) -> Result<(), Self::Error> {
+        let low = Self::low();
+        let high = Self::high();
+
+        if (low..=high).contains(value) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(low, high))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "duration between {low:?} and {high:?}",
+            low = Self::low(),
+            high = Self::high()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "duration::between<{low:?}, {high:?}>",
+            low = Self::low(),
+            high = Self::high()
+        )
+    }
+}
+
+/// Represents errors that occur when the duration is zero.
+#[derive(Debug, Error, Default)]
+#[error("received zero duration")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(code(duration::non_zero), help("make sure the duration is non-zero"))
+)]
+pub struct ZeroDurationError;
+
+impl ZeroDurationError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks whether the given duration is non-zero.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use refinement_types::{Predicate, duration::NonZeroDuration};
+///
+/// assert!(NonZeroDuration::is_satisfied(&Duration::from_nanos(1)));
+///
+/// assert!(!NonZeroDuration::is_satisfied(&Duration::ZERO));
+/// ```
+pub struct NonZeroDuration {
+    private: PhantomData<()>,
+}
+
+impl Predicate<Duration> for NonZeroDuration {
+    type Error = ZeroDurationError;
+
+    fn check(value: &Duration) -> Result<(), Self::Error> {
+        if value.is_zero() {
+            Err(Self::Error::new())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("non-zero duration")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("duration::non_zero")
+    }
+}
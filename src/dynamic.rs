@@ -0,0 +1,432 @@
+//! Dynamic refinements.
+//!
+//! Predicates in [`core`] are purely type-level, which means they can not depend
+//! on values known only at runtime (for instance, configured limits).
+//! This module provides [`DynRefinement`], which is checked against predicate objects
+//! provided at construction instead.
+//!
+//! [`core`]: crate::core
+
+use core::{fmt, ops::Deref};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::{
+    core::{EXPECTED, ErrorCore},
+    static_str::StaticStr,
+};
+
+#[cfg(feature = "std")]
+use core::str::FromStr;
+
+#[cfg(feature = "std")]
+use std::env::{self, VarError};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// Represents errors produced by dynamic predicates.
+#[derive(Debug)]
+pub struct DynError {
+    /// The expectation of the predicate.
+    pub expected: StaticStr,
+    /// The details of the failure, for instance, the runtime parameters of the predicate.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub details: Option<String>,
+}
+
+impl DynError {
+    /// Constructs [`Self`].
+    pub const fn new(expected: StaticStr) -> Self {
+        Self {
+            expected,
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            details: None,
+        }
+    }
+
+    /// Constructs [`Self`] with the given details.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub const fn with_details(expected: StaticStr, details: String) -> Self {
+        Self {
+            expected,
+            details: Some(details),
+        }
+    }
+}
+
+impl fmt::Display for DynError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{EXPECTED} {expected}", expected = self.expected)?;
+
+        #[cfg(any(feature = "alloc", feature = "std"))]
+        if let Some(details) = &self.details {
+            write!(formatter, " ({details})")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ErrorCore for DynError {}
+
+#[cfg(feature = "diagnostics")]
+impl Diagnostic for DynError {
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("dynamic"))
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new(format!(
+            "make sure the value is {expected}",
+            expected = self.expected
+        )))
+    }
+}
+
+/// Represents predicates checked at runtime.
+pub trait DynPredicate<T: ?Sized> {
+    /// Checks if the value of type `T` satisfies the predicate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DynError`] if the value does not satisfy the predicate.
+    fn check(&self, value: &T) -> Result<(), DynError>;
+
+    /// Checks whether the given value satisfies the predicate.
+    fn is_satisfied(&self, value: &T) -> bool {
+        self.check(value).is_ok()
+    }
+}
+
+/// Represents dynamic predicates defined by functions.
+///
+/// The function returns whether the value satisfies the predicate,
+/// and the expectation is used to construct [`DynError`] if it does not.
+pub struct Dynamic<F> {
+    expected: StaticStr,
+    function: F,
+}
+
+impl<F> Dynamic<F> {
+    /// Constructs [`Self`].
+    pub const fn new(expected: StaticStr, function: F) -> Self {
+        Self { expected, function }
+    }
+
+    /// Returns the expectation of the predicate.
+    pub const fn expected(&self) -> StaticStr {
+        self.expected
+    }
+}
+
+impl<T: ?Sized, F: Fn(&T) -> bool> DynPredicate<T> for Dynamic<F> {
+    fn check(&self, value: &T) -> Result<(), DynError> {
+        if (self.function)(value) {
+            Ok(())
+        } else {
+            Err(DynError::new(self.expected))
+        }
+    }
+}
+
+/// Represents values refined at runtime.
+///
+/// Values of this type are guaranteed to contain values of type `T`
+/// that satisfy the predicate object they were constructed with.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::dynamic::{DynRefinement, Dynamic};
+///
+/// let max = 5;
+///
+/// let short = Dynamic::new("string of at most 5 bytes", move |string: &&str| {
+///     string.len() <= max
+/// });
+///
+/// let name = DynRefinement::refine("nekit", &short).unwrap();
+///
+/// assert_eq!(*name, "nekit");
+///
+/// let error = DynRefinement::refine("nekitdev", &short).unwrap_err();
+///
+/// assert_eq!(error.value, "nekitdev");
+/// assert_eq!(error.error.expected, "string of at most 5 bytes");
+/// ```
+pub struct DynRefinement<'p, T> {
+    value: T,
+    predicate: &'p dyn DynPredicate<T>,
+}
+
+impl<T: fmt::Debug> fmt::Debug for DynRefinement<'_, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(formatter)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for DynRefinement<'_, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get().fmt(formatter)
+    }
+}
+
+impl<T: Clone> Clone for DynRefinement<'_, T> {
+    fn clone(&self) -> Self {
+        // SAFETY: by construction, the value satisfies the predicate
+        unsafe { Self::unchecked(self.get().clone(), self.predicate) }
+    }
+}
+
+impl<T> AsRef<T> for DynRefinement<'_, T> {
+    fn as_ref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T> Deref for DynRefinement<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+/// Represents dynamic refinement errors.
+///
+/// This error is constructed from the value that failed to satisfy the predicate
+/// and the error produced by the predicate.
+#[derive(Debug, Error)]
+#[error("{error}")]
+pub struct DynRefinementError<T: fmt::Debug> {
+    /// The value that failed to satisfy the predicate.
+    pub value: T,
+
+    /// The error produced by the predicate.
+    #[source]
+    pub error: DynError,
+}
+
+impl<T: fmt::Debug> DynRefinementError<T> {
+    /// Constructs [`Self`].
+    pub const fn new(value: T, error: DynError) -> Self {
+        Self { value, error }
+    }
+
+    /// Returns the contained value and the received error.
+    pub fn into_parts(self) -> (T, DynError) {
+        (self.value, self.error)
+    }
+}
+
+impl<'p, T> DynRefinement<'p, T> {
+    /// Constructs [`Self`] without checking the value.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the value satisfies the predicate.
+    pub const unsafe fn unchecked(value: T, predicate: &'p dyn DynPredicate<T>) -> Self {
+        Self { value, predicate }
+    }
+
+    /// Refines the given value using the given predicate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DynRefinementError`] if the value does not satisfy the predicate.
+    pub fn refine(
+        value: T,
+        predicate: &'p dyn DynPredicate<T>,
+    ) -> Result<Self, DynRefinementError<T>>
+    where
+        T: fmt::Debug,
+    {
+        match predicate.check(&value) {
+            // SAFETY: the value satisfies the predicate if the check is successful
+            Ok(()) => Ok(unsafe { Self::unchecked(value, predicate) }),
+            Err(error) => Err(DynRefinementError::new(value, error)),
+        }
+    }
+
+    /// Maps the value of the refinement, checking it against the same predicate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DynRefinementError`] if the resulting value does not satisfy the predicate.
+    pub fn map<F: FnOnce(T) -> T>(self, function: F) -> Result<Self, DynRefinementError<T>>
+    where
+        T: fmt::Debug,
+    {
+        let predicate = self.predicate;
+
+        Self::refine(function(self.take()), predicate)
+    }
+
+    /// Returns the predicate of the refinement.
+    pub const fn predicate(&self) -> &'p dyn DynPredicate<T> {
+        self.predicate
+    }
+
+    /// Takes the value from the refinement.
+    pub fn take(self) -> T {
+        self.value
+    }
+
+    /// Returns a reference to the value of the refinement.
+    pub const fn get(&self) -> &T {
+        &self.value
+    }
+}
+
+/// Represents errors that occur when reading bounds from environment variables.
+#[cfg(feature = "std")]
+#[derive(Debug, Error)]
+pub enum EnvBoundError<E: ErrorCore + 'static> {
+    /// The environment variable is missing or is not valid unicode.
+    #[error("failed to read environment variable `{name}`")]
+    Missing {
+        /// The name of the environment variable.
+        name: StaticStr,
+        /// The error produced when reading the variable.
+        #[source]
+        error: VarError,
+    },
+    /// The environment variable can not be parsed.
+    #[error("failed to parse environment variable `{name}`")]
+    Invalid {
+        /// The name of the environment variable.
+        name: StaticStr,
+        /// The error produced when parsing the variable.
+        #[source]
+        error: E,
+    },
+}
+
+#[cfg(feature = "std")]
+impl<E: ErrorCore + 'static> EnvBoundError<E> {
+    /// Constructs [`Self::Missing`].
+    pub const fn missing(name: StaticStr, error: VarError) -> Self {
+        Self::Missing { name, error }
+    }
+
+    /// Constructs [`Self::Invalid`].
+    pub const fn invalid(name: StaticStr, error: E) -> Self {
+        Self::Invalid { name, error }
+    }
+
+    /// Returns the name of the environment variable.
+    pub const fn name(&self) -> StaticStr {
+        match self {
+            Self::Missing { name, .. } | Self::Invalid { name, .. } => name,
+        }
+    }
+}
+
+/// The expectation of [`EnvBound`] predicates.
+#[cfg(feature = "std")]
+pub const ENV_BOUND: StaticStr = "value less than or equal to the configured bound";
+
+/// Represents dynamic predicates checking that values are less than or equal to the bound
+/// read from the environment variable.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::dynamic::{DynPredicate, DynRefinement, EnvBound};
+///
+/// // SAFETY: this example is single-threaded
+/// unsafe { std::env::set_var("MAX_CONNECTIONS", "100") };
+///
+/// let max = EnvBound::<u32>::from_env("MAX_CONNECTIONS").unwrap();
+///
+/// assert_eq!(max.bound(), 100);
+///
+/// assert!(max.is_satisfied(&100));
+///
+/// let error = max.check(&101).unwrap_err();
+///
+/// assert_eq!(
+///     error.to_string(),
+///     "expected value less than or equal to the configured bound \
+///     (received 101, bound `MAX_CONNECTIONS` is 100)"
+/// );
+///
+/// let connections = DynRefinement::refine(42, &max).unwrap();
+///
+/// assert_eq!(*connections, 42);
+///
+/// // SAFETY: this example is single-threaded
+/// unsafe { std::env::set_var("MAX_CONNECTIONS", "many") };
+///
+/// assert!(EnvBound::<u32>::from_env("MAX_CONNECTIONS").is_err());
+/// assert!(EnvBound::<u32>::from_env("MISSING_MAX_CONNECTIONS").is_err());
+/// ```
+#[cfg(feature = "std")]
+pub struct EnvBound<N> {
+    name: StaticStr,
+    bound: N,
+}
+
+#[cfg(feature = "std")]
+impl<N> EnvBound<N> {
+    /// Constructs [`Self`] from the name of the environment variable and the bound.
+    pub const fn new(name: StaticStr, bound: N) -> Self {
+        Self { name, bound }
+    }
+
+    /// Returns the name of the environment variable.
+    pub const fn name(&self) -> StaticStr {
+        self.name
+    }
+
+    /// Returns the bound.
+    pub fn bound(&self) -> N
+    where
+        N: Copy,
+    {
+        self.bound
+    }
+}
+
+#[cfg(feature = "std")]
+impl<N: FromStr> EnvBound<N>
+where
+    N::Err: ErrorCore + 'static,
+{
+    /// Reads the bound from the environment variable with the given name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EnvBoundError`] if the variable is missing or can not be parsed.
+    pub fn from_env(name: StaticStr) -> Result<Self, EnvBoundError<N::Err>> {
+        let string = env::var(name).map_err(|error| EnvBoundError::missing(name, error))?;
+
+        let bound = string
+            .parse()
+            .map_err(|error| EnvBoundError::invalid(name, error))?;
+
+        Ok(Self::new(name, bound))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<N: PartialOrd + fmt::Display> DynPredicate<N> for EnvBound<N> {
+    fn check(&self, value: &N) -> Result<(), DynError> {
+        if *value <= self.bound {
+            Ok(())
+        } else {
+            Err(DynError::with_details(
+                ENV_BOUND,
+                format!(
+                    "received {value}, bound `{name}` is {bound}",
+                    name = self.name,
+                    bound = self.bound
+                ),
+            ))
+        }
+    }
+}
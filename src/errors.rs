@@ -0,0 +1,121 @@
+//! Fixed-capacity error accumulation.
+//!
+//! This module allows predicates to report multiple errors without requiring allocation.
+//! For instance, [`All`] collects errors of all unsatisfied predicates instead of the first one.
+//!
+//! [`All`]: crate::logic::All
+
+use core::{fmt, slice};
+
+use heapless::Vec;
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use crate::core::ErrorCore;
+
+/// Represents fixed-capacity collections of errors, storing at most `N` errors.
+///
+/// Errors pushed beyond the capacity are not stored, but are still counted in [`total`].
+///
+/// [`total`]: Self::total
+#[derive(Debug)]
+pub struct Errors<E, const N: usize> {
+    errors: Vec<E, N>,
+    total: usize,
+}
+
+impl<E, const N: usize> Default for Errors<E, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, const N: usize> Errors<E, N> {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self {
+            errors: Vec::new(),
+            total: 0,
+        }
+    }
+
+    /// Pushes the given error, storing it if there is capacity left.
+    pub fn push(&mut self, error: E) {
+        self.total += 1;
+
+        // errors beyond the capacity are counted, but not stored
+        let _ = self.errors.push(error);
+    }
+
+    /// Returns the stored errors.
+    pub fn as_slice(&self) -> &[E] {
+        self.errors.as_slice()
+    }
+
+    /// Returns the iterator over the stored errors.
+    pub fn iter(&self) -> slice::Iter<'_, E> {
+        self.as_slice().iter()
+    }
+
+    /// Returns the total number of errors pushed, including the ones not stored.
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the number of errors that were not stored due to the capacity.
+    pub fn skipped(&self) -> usize {
+        self.total - self.errors.len()
+    }
+
+    /// Checks whether no errors were pushed.
+    pub const fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+}
+
+impl<'e, E, const N: usize> IntoIterator for &'e Errors<E, N> {
+    type Item = &'e E;
+    type IntoIter = slice::Iter<'e, E>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<E: fmt::Display, const N: usize> fmt::Display for Errors<E, N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{total} errors occurred", total = self.total())?;
+
+        let mut first = true;
+
+        for error in self {
+            let separator = if first { ": " } else { "; " };
+
+            first = false;
+
+            write!(formatter, "{separator}{error}")?;
+        }
+
+        let skipped = self.skipped();
+
+        if skipped > 0 {
+            write!(formatter, " (and {skipped} more)")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: ErrorCore, const N: usize> ErrorCore for Errors<E, N> {}
+
+#[cfg(feature = "diagnostics")]
+impl<E: Diagnostic, const N: usize> Diagnostic for Errors<E, N> {
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("errors"))
+    }
+
+    fn related(&self) -> Option<Box<dyn Iterator<Item = &dyn Diagnostic> + '_>> {
+        Some(Box::new(self.iter().map(|error| error as &dyn Diagnostic)))
+    }
+}
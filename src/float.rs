@@ -0,0 +1,373 @@
+//! Predicates for floating point numbers.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::{core::Predicate, type_float::TypeFloat};
+
+/// Represents floating point numbers.
+pub trait Float: Copy {
+    /// Checks whether the value is normal, that is, neither zero, subnormal, infinite nor NaN.
+    fn normal(self) -> bool;
+
+    /// Checks whether the value is subnormal.
+    fn subnormal(self) -> bool;
+
+    /// Checks whether the value is finite and has no fractional part.
+    fn integer(self) -> bool;
+}
+
+macro_rules! float {
+    ($($float: ty),+ $(,)?) => {
+        $(
+            impl Float for $float {
+                fn normal(self) -> bool {
+                    self.is_normal()
+                }
+
+                fn subnormal(self) -> bool {
+                    self.is_subnormal()
+                }
+
+                fn integer(self) -> bool {
+                    // values of this magnitude can not have fractional parts
+                    let bound = (1_u64 << (<$float>::MANTISSA_DIGITS - 1)) as $float;
+
+                    // since `fract` is not available in `core`, we truncate via casting instead,
+                    // which is lossless for values below the bound
+                    self.is_finite() && (self.abs() >= bound || (self as i64) as $float == self)
+                }
+            }
+        )+
+    };
+}
+
+float!(f32, f64);
+
+/// Represents errors that occur when the provided value is not normal.
+#[derive(Debug, Error, Default)]
+#[error("received non-normal float")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(float::normal),
+        help("make sure the value is neither zero, subnormal, infinite nor NaN")
+    )
+)]
+pub struct NormalError;
+
+impl NormalError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks whether the value is normal, that is, neither zero, subnormal, infinite nor NaN.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, float::IsNormal};
+///
+/// assert!(IsNormal::is_satisfied(&1.0_f64));
+///
+/// assert!(!IsNormal::is_satisfied(&0.0_f64));
+/// assert!(!IsNormal::is_satisfied(&(f64::MIN_POSITIVE / 2.0)));
+/// assert!(!IsNormal::is_satisfied(&f64::NAN));
+/// assert!(!IsNormal::is_satisfied(&f64::INFINITY));
+/// ```
+pub struct IsNormal {
+    private: PhantomData<()>,
+}
+
+impl<F: Float> Predicate<F> for IsNormal {
+    type Error = NormalError;
+
+    fn check(value: &F) -> Result<(), Self::Error> {
+        if value.normal() {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("normal float")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("float::normal")
+    }
+}
+
+/// Represents errors that occur when the provided value is not subnormal.
+#[derive(Debug, Error, Default)]
+#[error("received non-subnormal float")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(float::subnormal),
+        help(
+            "make sure the value is non-zero and less than the smallest normal value in magnitude"
+        )
+    )
+)]
+pub struct SubnormalError;
+
+impl SubnormalError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks whether the value is subnormal.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, float::IsSubnormal};
+///
+/// assert!(IsSubnormal::is_satisfied(&(f64::MIN_POSITIVE / 2.0)));
+///
+/// assert!(!IsSubnormal::is_satisfied(&1.0_f64));
+/// assert!(!IsSubnormal::is_satisfied(&0.0_f64));
+/// assert!(!IsSubnormal::is_satisfied(&f64::NAN));
+/// ```
+pub struct IsSubnormal {
+    private: PhantomData<()>,
+}
+
+impl<F: Float> Predicate<F> for IsSubnormal {
+    type Error = SubnormalError;
+
+    fn check(value: &F) -> Result<(), Self::Error> {
+        if value.subnormal() {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("subnormal float")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("float::subnormal")
+    }
+}
+
+/// Represents errors that occur when the provided value is not an integer.
+#[derive(Debug, Error, Default)]
+#[error("received non-integer float")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(float::integer),
+        help("make sure the value is finite and has no fractional part")
+    )
+)]
+pub struct IntegerError;
+
+impl IntegerError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks whether the value is an integer, that is, finite and without fractional part.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, float::IsInteger};
+///
+/// assert!(IsInteger::is_satisfied(&3.0_f64));
+/// assert!(IsInteger::is_satisfied(&-0.0_f64));
+/// assert!(IsInteger::is_satisfied(&1e300_f64));
+///
+/// assert!(!IsInteger::is_satisfied(&3.5_f64));
+/// assert!(!IsInteger::is_satisfied(&f64::INFINITY));
+/// assert!(!IsInteger::is_satisfied(&f64::NAN));
+/// ```
+pub struct IsInteger {
+    private: PhantomData<()>,
+}
+
+impl<F: Float> Predicate<F> for IsInteger {
+    type Error = IntegerError;
+
+    fn check(value: &F) -> Result<(), Self::Error> {
+        if value.integer() {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("integer float")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("float::integer")
+    }
+}
+
+/// Represents errors that occur when the slice is not a valid probability distribution.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+pub enum ProbabilityDistributionError {
+    /// The slice is empty.
+    #[error("received empty probability distribution")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(float::probability_distribution::empty),
+            help("make sure the distribution has at least one element")
+        )
+    )]
+    Empty,
+    /// Some element is not in the `[0, 1]` range (or is NaN).
+    #[error("received probability {value} at index {index} outside of [0, 1]")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(float::probability_distribution::range),
+            help("make sure each probability is in the [0, 1] range")
+        )
+    )]
+    Range {
+        /// The index of the invalid element.
+        index: usize,
+        /// The invalid element.
+        value: f64,
+    },
+    /// The elements do not sum to `1` within the epsilon.
+    #[error("received probabilities summing to {sum}, expected 1 within {epsilon}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(float::probability_distribution::sum),
+            help("make sure the probabilities sum to 1 within {epsilon}")
+        )
+    )]
+    Sum {
+        /// The sum of the elements.
+        sum: f64,
+        /// The allowed epsilon (the `E`).
+        epsilon: f64,
+    },
+}
+
+impl ProbabilityDistributionError {
+    /// Constructs [`Self::Empty`].
+    pub const fn empty() -> Self {
+        Self::Empty
+    }
+
+    /// Constructs [`Self::Range`].
+    pub const fn range(index: usize, value: f64) -> Self {
+        Self::Range { index, value }
+    }
+
+    /// Constructs [`Self::Sum`].
+    pub const fn sum(sum: f64, epsilon: f64) -> Self {
+        Self::Sum { sum, epsilon }
+    }
+}
+
+/// Checks whether the slice is a valid probability distribution, that is, whether it is
+/// non-empty, each element is in the `[0, 1]` range and the elements sum to `1` within `E`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     float::{ProbabilityDistribution, ProbabilityDistributionError},
+///     type_float,
+/// };
+///
+/// type_float!(Epsilon = 1e-9);
+///
+/// type Distribution = ProbabilityDistribution<Epsilon>;
+///
+/// assert!(Distribution::is_satisfied(&[0.5, 0.5][..]));
+/// assert!(Distribution::is_satisfied(&[1.0][..]));
+///
+/// let error = Distribution::check(&[0.3, 0.3, 0.3][..]).unwrap_err();
+///
+/// assert!(matches!(error, ProbabilityDistributionError::Sum { .. }));
+///
+/// let error = Distribution::check(&[0.5, f64::NAN][..]).unwrap_err();
+///
+/// assert!(matches!(error, ProbabilityDistributionError::Range { index: 1, .. }));
+///
+/// let error = Distribution::check(&[][..]).unwrap_err();
+///
+/// assert!(matches!(error, ProbabilityDistributionError::Empty));
+/// ```
+pub struct ProbabilityDistribution<E: TypeFloat + ?Sized> {
+    epsilon: PhantomData<E>,
+}
+
+impl<E: TypeFloat + ?Sized> ProbabilityDistribution<E> {
+    /// Returns the allowed epsilon.
+    pub const fn epsilon() -> f64 {
+        E::VALUE
+    }
+}
+
+impl<E: TypeFloat + ?Sized> Predicate<[f64]> for ProbabilityDistribution<E> {
+    type Error = ProbabilityDistributionError;
+
+    fn check(value: &[f64]) -> Result<(), Self::Error> {
+        if value.is_empty() {
+            return Err(Self::Error::empty());
+        }
+
+        if let Some((index, &probability)) = value
+            .iter()
+            .enumerate()
+            .find(|(_, probability)| !(0.0..=1.0).contains(*probability))
+        {
+            return Err(Self::Error::range(index, probability));
+        }
+
+        let sum: f64 = value.iter().sum();
+        let epsilon = Self::epsilon();
+
+        if (sum - 1.0).abs() <= epsilon {
+            Ok(())
+        } else {
+            Err(Self::Error::sum(sum, epsilon))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "probability distribution within {epsilon}",
+            epsilon = Self::epsilon()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "float::probability_distribution<{epsilon}>",
+            epsilon = Self::epsilon()
+        )
+    }
+}
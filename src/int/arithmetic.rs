@@ -0,0 +1,186 @@
+//! Arithmetic on refined integers.
+
+use core::fmt;
+
+use crate::{
+    core::{Error, ErrorCore, Predicate, Refinement},
+    type_str::TypeStr,
+};
+
+/// Represents errors that occur when performing checked arithmetic on refinements.
+#[derive(Debug)]
+pub enum ArithmeticError<E> {
+    /// The operation overflowed.
+    Overflow,
+    /// The resulting value does not satisfy the predicate.
+    Refine(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ArithmeticError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Overflow => formatter.write_str("arithmetic overflow"),
+            Self::Refine(error) => error.fmt(formatter),
+        }
+    }
+}
+
+impl<E: ErrorCore> ErrorCore for ArithmeticError<E> {
+    fn source(&self) -> Option<&(dyn ErrorCore + 'static)> {
+        match self {
+            Self::Overflow => None,
+            Self::Refine(error) => error.source(),
+        }
+    }
+}
+
+/// Represents refined integers, providing arithmetic operations that re-refine the results.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Refinement,
+///     int::{ArithmeticError, RefinedInt, u8},
+/// };
+///
+/// type Percent = Refinement<u8, u8::LessOrEqual<100>>;
+///
+/// let percent = Percent::refine(42).unwrap();
+///
+/// assert_eq!(*percent.checked_add(13).unwrap(), 55);
+///
+/// assert!(matches!(
+///     percent.checked_add(69),
+///     Err(ArithmeticError::Refine(_)),
+/// ));
+///
+/// assert!(matches!(
+///     percent.checked_add(255),
+///     Err(ArithmeticError::Overflow),
+/// ));
+///
+/// assert!(matches!(
+///     percent.checked_sub(69),
+///     Err(ArithmeticError::Overflow),
+/// ));
+///
+/// assert_eq!(*percent.checked_sub(13).unwrap(), 29);
+///
+/// assert_eq!(*percent.checked_mul(2).unwrap(), 84);
+///
+/// assert!(matches!(
+///     percent.checked_mul(3),
+///     Err(ArithmeticError::Refine(_)),
+/// ));
+///
+/// assert!(matches!(
+///     percent.checked_mul(7),
+///     Err(ArithmeticError::Overflow),
+/// ));
+///
+/// assert_eq!(*percent.saturating_add(13).unwrap(), 55);
+///
+/// assert!(percent.saturating_add(255).is_err());
+///
+/// assert_eq!(*percent.wrapping_add(255).unwrap(), 41);
+///
+/// assert!(percent.wrapping_add(100).is_err());
+/// ```
+pub trait RefinedInt: Sized {
+    /// The underlying integer type.
+    type Int;
+
+    /// The error type returned when the resulting value does not satisfy the predicate.
+    type Error;
+
+    /// Adds `other` to the value, checking for overflow, and refines the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError::Overflow`] if the operation overflows, and
+    /// [`ArithmeticError::Refine`] if the result does not satisfy the predicate.
+    fn checked_add(self, other: Self::Int) -> Result<Self, ArithmeticError<Self::Error>>;
+
+    /// Subtracts `other` from the value, checking for overflow, and refines the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError::Overflow`] if the operation overflows, and
+    /// [`ArithmeticError::Refine`] if the result does not satisfy the predicate.
+    fn checked_sub(self, other: Self::Int) -> Result<Self, ArithmeticError<Self::Error>>;
+
+    /// Multiplies the value by `other`, checking for overflow, and refines the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithmeticError::Overflow`] if the operation overflows, and
+    /// [`ArithmeticError::Refine`] if the result does not satisfy the predicate.
+    fn checked_mul(self, other: Self::Int) -> Result<Self, ArithmeticError<Self::Error>>;
+
+    /// Adds `other` to the value, saturating at the numeric bounds, and refines the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::Error`] if the result does not satisfy the predicate.
+    fn saturating_add(self, other: Self::Int) -> Result<Self, Self::Error>;
+
+    /// Adds `other` to the value, wrapping around at the numeric bounds, and refines the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::Error`] if the result does not satisfy the predicate.
+    fn wrapping_add(self, other: Self::Int) -> Result<Self, Self::Error>;
+}
+
+macro_rules! refined_int {
+    ($($int: ty),+ $(,)?) => {
+        $(
+            impl<P: Predicate<$int> + ?Sized, C: TypeStr + ?Sized> RefinedInt
+                for Refinement<$int, P, C>
+            {
+                type Int = $int;
+                type Error = Error<$int, P, C>;
+
+                fn checked_add(
+                    self,
+                    other: Self::Int,
+                ) -> Result<Self, ArithmeticError<Self::Error>> {
+                    let value = self.take().checked_add(other).ok_or(ArithmeticError::Overflow)?;
+
+                    Self::refine(value).map_err(ArithmeticError::Refine)
+                }
+
+                fn checked_sub(
+                    self,
+                    other: Self::Int,
+                ) -> Result<Self, ArithmeticError<Self::Error>> {
+                    let value = self.take().checked_sub(other).ok_or(ArithmeticError::Overflow)?;
+
+                    Self::refine(value).map_err(ArithmeticError::Refine)
+                }
+
+                fn checked_mul(
+                    self,
+                    other: Self::Int,
+                ) -> Result<Self, ArithmeticError<Self::Error>> {
+                    let value = self.take().checked_mul(other).ok_or(ArithmeticError::Overflow)?;
+
+                    Self::refine(value).map_err(ArithmeticError::Refine)
+                }
+
+                fn saturating_add(self, other: Self::Int) -> Result<Self, Self::Error> {
+                    Self::refine(self.take().saturating_add(other))
+                }
+
+                fn wrapping_add(self, other: Self::Int) -> Result<Self, Self::Error> {
+                    Self::refine(self.take().wrapping_add(other))
+                }
+            }
+        )+
+    };
+}
+
+refined_int!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
@@ -0,0 +1,137 @@
+//! Predicates based on divisibility by multiple divisors.
+
+use core::fmt;
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use crate::core::ErrorCore;
+
+#[doc(hidden)]
+pub mod import {
+    pub use core::{fmt, marker::PhantomData};
+}
+
+/// Represents errors that occur when the provided value is not divisible by some divisor.
+///
+/// This is returned by predicates generated with [`divisible_by_all!`].
+///
+/// [`divisible_by_all!`]: crate::divisible_by_all
+#[derive(Debug)]
+pub struct DivisibleByAllError<I> {
+    /// The received value.
+    pub value: I,
+    /// The first divisor that does not divide the value.
+    pub divisor: I,
+}
+
+impl<I> DivisibleByAllError<I> {
+    /// Constructs [`Self`].
+    pub const fn new(value: I, divisor: I) -> Self {
+        Self { value, divisor }
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for DivisibleByAllError<I> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "received value {value} not divisible by {divisor}",
+            value = self.value,
+            divisor = self.divisor
+        )
+    }
+}
+
+impl<I: fmt::Debug + fmt::Display> ErrorCore for DivisibleByAllError<I> {}
+
+#[cfg(feature = "diagnostics")]
+impl<I: fmt::Debug + fmt::Display> Diagnostic for DivisibleByAllError<I> {
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("int::divisible_by_all"))
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("make sure the value is divisible by all divisors"))
+    }
+}
+
+/// Generates predicates that check whether integers are divisible by all given divisors.
+///
+/// Divisors equal to zero never divide values.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, divisible_by_all};
+///
+/// divisible_by_all!(ByTwelve for u32 = [3, 4]);
+///
+/// assert!(ByTwelve::is_satisfied(&12));
+/// assert!(ByTwelve::is_satisfied(&24));
+///
+/// assert_eq!(ByTwelve::check(&8).unwrap_err().divisor, 3);
+/// assert_eq!(ByTwelve::check(&6).unwrap_err().divisor, 4);
+///
+/// divisible_by_all!(ByNegativeOne for i32 = [-1]);
+///
+/// assert!(ByNegativeOne::is_satisfied(&i32::MIN));
+///
+/// divisible_by_all!(ByZero for i32 = [0]);
+///
+/// assert!(!ByZero::is_satisfied(&0));
+/// ```
+///
+/// Is roughly equivalent to `And<u32::Divisible<3>, u32::Divisible<4>>`,
+/// except that the error is combined into [`DivisibleByAllError`].
+#[macro_export]
+macro_rules! divisible_by_all {
+    (
+        $vis: vis $name: ident for $int: ty = [$($divisor: expr),+ $(,)?] $(=> $doc: expr)?
+    ) => {
+        $(
+            #[doc = $doc]
+        )?
+        $vis struct $name {
+            private: $crate::int::divisible::import::PhantomData<()>,
+        }
+
+        impl $name {
+            /// The divisors to check against.
+            pub const DIVISORS: &'static [$int] = &[$($divisor),+];
+        }
+
+        impl $crate::core::Predicate<$int> for $name {
+            type Error = $crate::int::divisible::DivisibleByAllError<$int>;
+
+            fn check(value: &$int) -> Result<(), Self::Error> {
+                Self::DIVISORS
+                    .iter()
+                    .find(|divisor| **divisor == 0 || value.wrapping_rem(**divisor) != 0)
+                    .map_or(Ok(()), |divisor| Err(Self::Error::new(*value, *divisor)))
+            }
+
+            fn expect(
+                formatter: &mut $crate::int::divisible::import::fmt::Formatter<'_>,
+            ) -> $crate::int::divisible::import::fmt::Result {
+                write!(
+                    formatter,
+                    "{int} divisible by all of {divisors:?}",
+                    int = stringify!($int),
+                    divisors = Self::DIVISORS,
+                )
+            }
+
+            fn expect_code(
+                formatter: &mut $crate::int::divisible::import::fmt::Formatter<'_>,
+            ) -> $crate::int::divisible::import::fmt::Result {
+                write!(
+                    formatter,
+                    "{int}::divisible_by_all<{divisors:?}>",
+                    int = stringify!($int),
+                    divisors = Self::DIVISORS,
+                )
+            }
+        }
+    };
+}
@@ -1,5 +1,5 @@
 pub(crate) mod import {
-    pub use core::{fmt, marker::PhantomData};
+    pub use core::{any, fmt, marker::PhantomData};
 
     pub use paste::paste;
     pub use thiserror::Error;
@@ -367,6 +367,152 @@ macro_rules! modulo {
 
 pub(crate) use modulo;
 
+macro_rules! aligned {
+    ($int: ty) => {
+        /// Represents errors that occur when the provided value is not aligned to [`align`].
+        ///
+        /// [`align`]: Self::align
+        #[derive(Debug, $crate::int::macros::import::Error)]
+        #[error("received {int} not aligned to {align}", int = stringify!($int))]
+        #[cfg_attr(
+            feature = "diagnostics",
+            derive($crate::int::macros::import::Diagnostic),
+            diagnostic(
+                code(int::$int::aligned),
+                help("make sure the value is a multiple of {align}")
+            )
+        )]
+        pub struct AlignedToError {
+            /// The expected alignment (the `A`).
+            pub align: $int,
+        }
+
+        impl AlignedToError {
+            /// Constructs [`Self`].
+            pub const fn new(align: $int) -> Self {
+                Self { align }
+            }
+        }
+
+        /// Checks whether the given value is aligned to `A`, that is, is a multiple of `A`.
+        ///
+        /// The alignment `A` is required to be a power of two, which is checked at compile time.
+        /// This allows checking via bitmask, which is faster than [`Divisible`].
+        pub struct AlignedTo<const A: $int> {
+            private: $crate::int::macros::import::PhantomData<()>,
+        }
+
+        impl<const A: $int> $crate::core::Predicate<$int> for AlignedTo<A> {
+            type Error = AlignedToError;
+
+            fn check(value: &$int) -> Result<(), Self::Error> {
+                const {
+                    assert!(A > 0 && A & (A - 1) == 0, "expected power of two alignment");
+                }
+
+                if *value & (A - 1) == 0 {
+                    Ok(())
+                } else {
+                    Err(Self::Error::new(A))
+                }
+            }
+
+            fn expect(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(formatter, "{int} aligned to {A}", int = stringify!($int))
+            }
+
+            fn expect_code(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(formatter, "{int}::aligned<{A}>", int = stringify!($int))
+            }
+        }
+    };
+}
+
+pub(crate) use aligned;
+
+macro_rules! fits_in {
+    ($int: ty) => {
+        /// Represents errors that occur when the provided value is not representable
+        /// in the [`target`] type.
+        ///
+        /// [`target`]: Self::target
+        #[derive(Debug, $crate::int::macros::import::Error)]
+        #[error("received {int} {value} not representable in `{target}`", int = stringify!($int))]
+        #[cfg_attr(
+            feature = "diagnostics",
+            derive($crate::int::macros::import::Diagnostic),
+            diagnostic(
+                code(int::$int::fits_in),
+                help("make sure the value is representable in `{target}`")
+            )
+        )]
+        pub struct FitsInError {
+            /// The received value.
+            pub value: $int,
+            /// The name of the target type (the `U`).
+            pub target: $crate::static_str::StaticStr,
+        }
+
+        impl FitsInError {
+            /// Constructs [`Self`].
+            pub const fn new(value: $int, target: $crate::static_str::StaticStr) -> Self {
+                Self { value, target }
+            }
+        }
+
+        /// Checks whether the given value is representable in `U`,
+        /// that is, whether it can be converted to `U` without loss.
+        ///
+        /// This is useful to validate values before narrowing conversions.
+        pub struct FitsIn<U: ?Sized> {
+            target: $crate::int::macros::import::PhantomData<U>,
+        }
+
+        impl<U: TryFrom<$int>> $crate::core::Predicate<$int> for FitsIn<U> {
+            type Error = FitsInError;
+
+            fn check(value: &$int) -> Result<(), Self::Error> {
+                if U::try_from(*value).is_ok() {
+                    Ok(())
+                } else {
+                    Err(Self::Error::new(
+                        *value,
+                        $crate::int::macros::import::any::type_name::<U>(),
+                    ))
+                }
+            }
+
+            fn expect(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(
+                    formatter,
+                    "{int} representable in `{target}`",
+                    int = stringify!($int),
+                    target = $crate::int::macros::import::any::type_name::<U>(),
+                )
+            }
+
+            fn expect_code(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(
+                    formatter,
+                    "{int}::fits_in<{target}>",
+                    int = stringify!($int),
+                    target = $crate::int::macros::import::any::type_name::<U>(),
+                )
+            }
+        }
+    };
+}
+
+pub(crate) use fits_in;
+
 macro_rules! divisible {
     ($int: ty) => {
         /// Checks whether the given value is divisible by `D`.
@@ -382,6 +528,214 @@ macro_rules! divisible {
 
 pub(crate) use divisible;
 
+macro_rules! within_percent {
+    ($int: ty) => {
+        /// Represents errors that occur when the provided value is not within
+        /// [`percent`](Self::percent)% of [`target`](Self::target).
+        #[derive(Debug, $crate::int::macros::import::Error)]
+        #[error("received {int} not within {percent}% of {target}", int = stringify!($int))]
+        #[cfg_attr(
+            feature = "diagnostics",
+            derive($crate::int::macros::import::Diagnostic),
+            diagnostic(
+                code(int::$int::within_percent),
+                help("make sure the value is within {percent}% of {target}")
+            )
+        )]
+        pub struct WithinPercentError {
+            /// The target value (the `N`).
+            pub target: $int,
+            /// The percentage (the `P`).
+            pub percent: $int,
+        }
+
+        impl WithinPercentError {
+            /// Constructs [`Self`].
+            pub const fn new(target: $int, percent: $int) -> Self {
+                Self { target, percent }
+            }
+        }
+
+        /// Checks whether the given value is within `P` percent of `N`.
+        ///
+        /// The check passes when `|value - N| * 100 <= |N| * |P|`, meaning the sign of `P`
+        /// is ignored. The comparison is exact: there is no intermediate rounding,
+        /// and it can not overflow.
+        pub struct WithinPercent<const N: $int, const P: $int> {
+            private: $crate::int::macros::import::PhantomData<()>,
+        }
+
+        impl<const N: $int, const P: $int> $crate::core::Predicate<$int> for WithinPercent<N, P> {
+            type Error = WithinPercentError;
+
+            #[allow(clippy::cast_lossless)] // not lossless for all integer types
+            fn check(value: &$int) -> Result<(), Self::Error> {
+                let difference = value.abs_diff(N) as u128;
+                let magnitude = N.abs_diff(0) as u128;
+                let percent = P.abs_diff(0) as u128;
+
+                if $crate::int::math::within_percent(difference, magnitude, percent) {
+                    Ok(())
+                } else {
+                    Err(Self::Error::new(N, P))
+                }
+            }
+
+            fn expect(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(
+                    formatter,
+                    "{int} within {P}% of {N}",
+                    int = stringify!($int)
+                )
+            }
+
+            fn expect_code(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(
+                    formatter,
+                    "{int}::within_percent<{N}, {P}>",
+                    int = stringify!($int)
+                )
+            }
+        }
+    };
+}
+
+pub(crate) use within_percent;
+
+macro_rules! shifts {
+    ($int: ty) => {
+        /// Checks whether the given [`prim@u32`] shift amount is less than
+        /// the number of bits in this integer type, meaning shifting by it does not overflow.
+        pub type ValidShift = $crate::int::u32::Less<{ <$int>::BITS }>;
+    };
+}
+
+pub(crate) use shifts;
+
+macro_rules! positions {
+    ($int: ty) => {
+        /// Represents errors that occur when the provided [`prim@u32`] value
+        /// is not a valid bit position.
+        #[derive(Debug, $crate::int::macros::import::Error)]
+        #[error("received bit position {position}, expected less than {bits}")]
+        #[cfg_attr(
+            feature = "diagnostics",
+            derive($crate::int::macros::import::Diagnostic),
+            diagnostic(
+                code(int::$int::bit_position),
+                help("make sure the position is less than {bits}")
+            )
+        )]
+        pub struct BitPositionError {
+            /// The received position.
+            pub position: u32,
+            /// The number of bits in the integer type.
+            pub bits: u32,
+        }
+
+        impl BitPositionError {
+            /// Constructs [`Self`].
+            pub const fn new(position: u32) -> Self {
+                Self {
+                    position,
+                    bits: <$int>::BITS,
+                }
+            }
+        }
+
+        /// Checks whether the given [`prim@u32`] value is a valid position of a single bit
+        /// in this integer type, that is, whether it is less than the number of bits.
+        ///
+        /// This is semantically different from [`ValidShift`], even though the check is the same.
+        pub struct BitPosition {
+            private: $crate::int::macros::import::PhantomData<()>,
+        }
+
+        impl $crate::core::Predicate<u32> for BitPosition {
+            type Error = BitPositionError;
+
+            fn check(value: &u32) -> Result<(), Self::Error> {
+                if *value < <$int>::BITS {
+                    Ok(())
+                } else {
+                    Err(Self::Error::new(*value))
+                }
+            }
+
+            fn expect(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(formatter, "bit position in {int}", int = stringify!($int))
+            }
+
+            fn expect_code(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(formatter, "{int}::bit_position", int = stringify!($int))
+            }
+        }
+    };
+}
+
+pub(crate) use positions;
+
+macro_rules! clamp {
+    ($int: ty) => {
+        impl<const M: $int, const N: $int, C: $crate::type_str::TypeStr + ?Sized>
+            $crate::core::Refinement<$int, Closed<M, N>, C>
+        {
+            /// Clamps the given value into the `[M, N]` interval, returning the refinement.
+            ///
+            /// Unlike [`refine`], this function never fails.
+            ///
+            /// The interval is required to be non-empty (`M <= N`), which is checked at compile time.
+            ///
+            /// [`refine`]: Self::refine
+            pub fn clamp(value: $int) -> Self {
+                const { assert!(M <= N, "expected non-empty interval") };
+
+                // SAFETY: the clamped value is within the interval
+                unsafe { Self::unchecked(Ord::clamp(value, M, N)) }
+            }
+        }
+    };
+}
+
+pub(crate) use clamp;
+
+macro_rules! discriminant {
+    ($int: ty) => {
+        /// Checks whether the given value is within the contiguous `[M, N]` range
+        /// of enum discriminants.
+        ///
+        /// This is equivalent to [`Closed<M, N>`], named for decoding enums.
+        pub type ContiguousDiscriminant<const M: $int, const N: $int> = Closed<M, N>;
+
+        impl<const M: $int, const N: $int, C: $crate::type_str::TypeStr + ?Sized>
+            $crate::core::Refinement<$int, ContiguousDiscriminant<M, N>, C>
+        {
+            /// Converts the discriminant into the enum `E`, without resorting to `transmute`.
+            ///
+            /// The conversion is delegated to [`TryFrom`] implementations of `E`,
+            /// which are expected to succeed on every value in the `[M, N]` range.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`TryFrom::Error`] if the conversion fails, which means that
+            /// the enum does not cover the entire range.
+            pub fn checked_into<E: TryFrom<$int>>(self) -> Result<E, E::Error> {
+                E::try_from(self.take())
+            }
+        }
+    };
+}
+
+pub(crate) use discriminant;
+
 macro_rules! common {
     ($int: ty) => {
         $crate::int::macros::comparing!($int);
@@ -389,14 +743,258 @@ macro_rules! common {
         $crate::int::macros::zeros!($int);
         $crate::int::macros::modulo!($int);
         $crate::int::macros::divisible!($int);
+        $crate::int::macros::aligned!($int);
+        $crate::int::macros::within_percent!($int);
+        $crate::int::macros::shifts!($int);
+        $crate::int::macros::positions!($int);
+        $crate::int::macros::clamp!($int);
+        $crate::int::macros::discriminant!($int);
+        $crate::int::macros::fits_in!($int);
     };
 }
 
 pub(crate) use common;
 
+macro_rules! power_of_two {
+    ($int: ty) => {
+        /// Represents errors that occur when the provided value is not a power of two.
+        #[derive(Debug, $crate::int::macros::import::Error, Default)]
+        #[error("received {int} that is not a power of two", int = stringify!($int))]
+        #[cfg_attr(
+            feature = "diagnostics",
+            derive($crate::int::macros::import::Diagnostic),
+            diagnostic(
+                code(int::$int::power_of_two),
+                help("make sure the value is a power of two")
+            )
+        )]
+        pub struct PowerOfTwoError;
+
+        impl PowerOfTwoError {
+            /// Constructs [`Self`].
+            pub const fn new() -> Self {
+                Self
+            }
+        }
+
+        /// Checks whether the given value is a power of two.
+        pub struct PowerOfTwo {
+            private: $crate::int::macros::import::PhantomData<()>,
+        }
+
+        impl $crate::core::Predicate<$int> for PowerOfTwo {
+            type Error = PowerOfTwoError;
+
+            fn check(value: &$int) -> Result<(), Self::Error> {
+                if value.is_power_of_two() {
+                    Ok(())
+                } else {
+                    Err(Self::Error::new())
+                }
+            }
+
+            fn expect(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(formatter, "power of two {int}", int = stringify!($int))
+            }
+
+            fn expect_code(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(formatter, "{int}::power_of_two", int = stringify!($int))
+            }
+        }
+    };
+}
+
+pub(crate) use power_of_two;
+
+macro_rules! triangular {
+    ($int: ty) => {
+        /// Represents errors that occur when the provided value is not a triangular number.
+        #[derive(Debug, $crate::int::macros::import::Error, Default)]
+        #[error("received {int} that is not triangular", int = stringify!($int))]
+        #[cfg_attr(
+            feature = "diagnostics",
+            derive($crate::int::macros::import::Diagnostic),
+            diagnostic(
+                code(int::$int::triangular),
+                help("make sure the value is equal to `n(n + 1) / 2` for some `n`")
+            )
+        )]
+        pub struct TriangularError;
+
+        impl TriangularError {
+            /// Constructs [`Self`].
+            pub const fn new() -> Self {
+                Self
+            }
+        }
+
+        /// Checks whether the given value is a triangular number,
+        /// that is, whether it is equal to `n(n + 1) / 2` for some `n`.
+        pub struct Triangular {
+            private: $crate::int::macros::import::PhantomData<()>,
+        }
+
+        impl $crate::core::Predicate<$int> for Triangular {
+            type Error = TriangularError;
+
+            fn check(value: &$int) -> Result<(), Self::Error> {
+                if $crate::int::math::is_triangular(*value as u128) {
+                    Ok(())
+                } else {
+                    Err(Self::Error::new())
+                }
+            }
+
+            fn expect(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(formatter, "triangular {int}", int = stringify!($int))
+            }
+
+            fn expect_code(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(formatter, "{int}::triangular", int = stringify!($int))
+            }
+        }
+    };
+}
+
+pub(crate) use triangular;
+
+macro_rules! same_digits {
+    ($int: ty) => {
+        /// Represents errors that occur when the provided value has different digits.
+        #[derive(Debug, $crate::int::macros::import::Error, Default)]
+        #[error("received {int} with different digits", int = stringify!($int))]
+        #[cfg_attr(
+            feature = "diagnostics",
+            derive($crate::int::macros::import::Diagnostic),
+            diagnostic(
+                code(int::$int::all_same_digits),
+                help("make sure all digits of the value are the same")
+            )
+        )]
+        pub struct AllSameDigitsError;
+
+        impl AllSameDigitsError {
+            /// Constructs [`Self`].
+            pub const fn new() -> Self {
+                Self
+            }
+        }
+
+        /// Checks whether all base-10 digits of the given value are the same.
+        pub struct AllSameDigits {
+            private: $crate::int::macros::import::PhantomData<()>,
+        }
+
+        impl $crate::core::Predicate<$int> for AllSameDigits {
+            type Error = AllSameDigitsError;
+
+            fn check(value: &$int) -> Result<(), Self::Error> {
+                let digit = value % 10;
+
+                let mut rest = value / 10;
+
+                while rest > 0 {
+                    if rest % 10 != digit {
+                        return Err(Self::Error::new());
+                    }
+
+                    rest /= 10;
+                }
+
+                Ok(())
+            }
+
+            fn expect(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(formatter, "{int} with same digits", int = stringify!($int))
+            }
+
+            fn expect_code(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(formatter, "{int}::all_same_digits", int = stringify!($int))
+            }
+        }
+    };
+}
+
+pub(crate) use same_digits;
+
+macro_rules! bit_width {
+    ($int: ty) => {
+        /// Represents errors that occur when the provided value is not a valid bit width.
+        #[derive(Debug, $crate::int::macros::import::Error, Default)]
+        #[error("not a valid bit width")]
+        #[cfg_attr(
+            feature = "diagnostics",
+            derive($crate::int::macros::import::Diagnostic),
+            diagnostic(
+                code(int::$int::bit_width),
+                help("make sure the value is a power of two from 1 to 64")
+            )
+        )]
+        pub struct BitWidthError;
+
+        impl BitWidthError {
+            /// Constructs [`Self`].
+            pub const fn new() -> Self {
+                Self
+            }
+        }
+
+        /// Checks whether the given value is a valid bit width,
+        /// that is, a power of two from `1` to `64` (inclusive).
+        pub struct BitWidth {
+            private: $crate::int::macros::import::PhantomData<()>,
+        }
+
+        impl $crate::core::Predicate<$int> for BitWidth {
+            type Error = BitWidthError;
+
+            fn check(value: &$int) -> Result<(), Self::Error> {
+                if value.is_power_of_two() && *value <= 64 {
+                    Ok(())
+                } else {
+                    Err(Self::Error::new())
+                }
+            }
+
+            fn expect(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                formatter.write_str("valid bit width")
+            }
+
+            fn expect_code(
+                formatter: &mut $crate::int::macros::import::fmt::Formatter<'_>,
+            ) -> $crate::int::macros::import::fmt::Result {
+                write!(formatter, "{int}::bit_width", int = stringify!($int))
+            }
+        }
+
+        /// Checks whether the given value is a valid shift amount for `B` bits.
+        pub type ShiftAmount<const B: $int> = Less<B>;
+    };
+}
+
+pub(crate) use bit_width;
+
 macro_rules! unsigned {
     ($int: ty) => {
         $crate::int::macros::common!($int);
+        $crate::int::macros::power_of_two!($int);
+        $crate::int::macros::triangular!($int);
+        $crate::int::macros::same_digits!($int);
+        $crate::int::macros::bit_width!($int);
     };
 }
 
@@ -430,10 +1028,14 @@ macro_rules! signed {
 pub(crate) use signed;
 
 macro_rules! unsigned_module {
-    ($int: ty => $name: ident) => {
+    ($int: ty => $name: ident $(with $specific: ident)?) => {
         #[doc = concat!("Predicates for ", $crate::int::macros::reference!($int), " values.")]
         pub mod $name {
             $crate::int::macros::unsigned!($int);
+
+            $(
+                pub use $crate::int::$specific::$name::*;
+            )?
         }
     };
 }
@@ -441,10 +1043,14 @@ macro_rules! unsigned_module {
 pub(crate) use unsigned_module;
 
 macro_rules! signed_module {
-    ($int: ty => $name: ident) => {
+    ($int: ty => $name: ident $(with $specific: ident)?) => {
         #[doc = concat!("Predicates for ", $crate::int::macros::reference!($int), " values.")]
         pub mod $name {
             $crate::int::macros::signed!($int);
+
+            $(
+                pub use $crate::int::$specific::$name::*;
+            )?
         }
     };
 }
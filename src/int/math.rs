@@ -0,0 +1,56 @@
+/// The `100` value, used for percentages.
+const HUNDRED: u128 = 100;
+
+/// Checks whether `difference * 100 <= magnitude * percent` without overflowing.
+///
+/// Splitting `magnitude = 100a + b` and `percent = 100c + d`, the right-hand side divided by
+/// `100` and rounded down is `100ac + ad + bc + bd / 100`, which is computed with saturation;
+/// saturated values can not be exceeded by `difference` anyway.
+pub(crate) const fn within_percent(difference: u128, magnitude: u128, percent: u128) -> bool {
+    let (a, b) = (magnitude / HUNDRED, magnitude % HUNDRED);
+    let (c, d) = (percent / HUNDRED, percent % HUNDRED);
+
+    let tolerance = a
+        .saturating_mul(c)
+        .saturating_mul(HUNDRED)
+        .saturating_add(a.saturating_mul(d))
+        .saturating_add(b.saturating_mul(c))
+        .saturating_add(b * d / HUNDRED);
+
+    difference <= tolerance
+}
+
+/// Computes `floor(sqrt(2 * value))` without overflowing.
+///
+/// Writing `2 * value = 4a + r` where `r` is either `0` or `2`, the result is either `2t`
+/// or `2t + 1`, where `t = floor(sqrt(a))`; the latter is the case if `(2t + 1)^2 <= 4a + r`,
+/// which is equivalent to `t^2 + t < a` for `r = 0`, and `t^2 + t <= a` for `r = 2`.
+const fn double_isqrt(value: u128) -> u128 {
+    let a = value / 2;
+    let odd = value % 2 == 1;
+
+    let t = a.isqrt();
+    let s = t * t + t;
+
+    if s < a || (odd && s == a) {
+        2 * t + 1
+    } else {
+        2 * t
+    }
+}
+
+/// Computes the `n`-th triangular number, that is, `n(n + 1) / 2`, returning [`None`] on overflow.
+const fn triangular(n: u128) -> Option<u128> {
+    if n.is_multiple_of(2) {
+        (n / 2).checked_mul(n + 1)
+    } else {
+        n.checked_mul(n.div_ceil(2))
+    }
+}
+
+/// Checks whether `value = n(n + 1) / 2` for some `n`.
+///
+/// Solving the quadratic equation, if such `n` exists, it is equal to `floor(sqrt(2 * value))`.
+pub(crate) const fn is_triangular(value: u128) -> bool {
+    matches!(triangular(double_isqrt(value)), Some(result) if result == value)
+}
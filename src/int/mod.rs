@@ -1,17 +1,270 @@
 //! Predicates for integers.
+//!
+//! # Decoding enums
+//!
+//! Each integer module provides the [`ContiguousDiscriminant<M, N>`] predicate,
+//! which can be used to validate discriminants of enums before converting them
+//! using [`checked_into`], without resorting to `transmute`:
+//!
+//! ```
+//! use refinement_types::{Refinement, int::u8::ContiguousDiscriminant};
+//!
+//! #[derive(Debug, PartialEq, Eq)]
+//! #[repr(u8)]
+//! enum Color {
+//!     Red = 1,
+//!     Green = 2,
+//!     Blue = 3,
+//! }
+//!
+//! impl TryFrom<u8> for Color {
+//!     type Error = u8;
+//!
+//!     fn try_from(value: u8) -> Result<Self, Self::Error> {
+//!         match value {
+//!             1 => Ok(Self::Red),
+//!             2 => Ok(Self::Green),
+//!             3 => Ok(Self::Blue),
+//!             other => Err(other),
+//!         }
+//!     }
+//! }
+//!
+//! type Discriminant = Refinement<u8, ContiguousDiscriminant<1, 3>>;
+//!
+//! let discriminant = Discriminant::refine(2).unwrap();
+//!
+//! assert_eq!(discriminant.checked_into::<Color>(), Ok(Color::Green));
+//!
+//! assert!(Discriminant::refine(0).is_err());
+//! assert!(Discriminant::refine(4).is_err());
+//! ```
+//!
+//! [`ContiguousDiscriminant<M, N>`]: u8::ContiguousDiscriminant
+//! [`checked_into`]: crate::core::Refinement::checked_into
+//!
+//! # Clamping
+//!
+//! Values can be clamped into [`Closed<M, N>`] intervals infallibly via [`clamp`]:
+//!
+//! ```
+//! use refinement_types::{Refinement, int::i16::Closed};
+//!
+//! type Volume = Refinement<i16, Closed<0, 100>>;
+//!
+//! assert_eq!(*Volume::clamp(-13), 0);
+//! assert_eq!(*Volume::clamp(42), 42);
+//! assert_eq!(*Volume::clamp(1337), 100);
+//! ```
+//!
+//! Clamping into empty intervals is rejected at compile time:
+//!
+//! ```compile_fail
+//! use refinement_types::{Refinement, int::i16::Closed};
+//!
+//! let value = Refinement::<i16, Closed<1, 0>>::clamp(0);
+//! ```
+//!
+//! [`Closed<M, N>`]: i16::Closed
+//! [`clamp`]: crate::core::Refinement::clamp
+//!
+//! # Tolerances
+//!
+//! Each integer module provides the [`WithinPercent<N, P>`] predicate, which checks
+//! whether values are within `P` percent of `N`, inclusive on both ends:
+//!
+//! ```
+//! use refinement_types::{Predicate, int::i32::WithinPercent};
+//!
+//! type Tolerance = WithinPercent<100, 10>;
+//!
+//! assert!(Tolerance::is_satisfied(&90));
+//! assert!(Tolerance::is_satisfied(&100));
+//! assert!(Tolerance::is_satisfied(&110));
+//!
+//! assert!(!Tolerance::is_satisfied(&89));
+//! assert!(!Tolerance::is_satisfied(&111));
+//!
+//! // no rounding is involved, so bounds that are not integers are exclusive
+//! type Fraction = WithinPercent<-15, 10>;
+//!
+//! assert!(Fraction::is_satisfied(&-16));
+//! assert!(!Fraction::is_satisfied(&-17));
+//! assert!(!Fraction::is_satisfied(&-13));
+//! ```
+//!
+//! [`WithinPercent<N, P>`]: i32::WithinPercent
+//!
+//! # Bits
+//!
+//! Each integer module provides the [`BitPosition`] and [`ValidShift`] predicates
+//! over [`prim@u32`] values, where the former checks for positions of single bits,
+//! and the latter checks for shift amounts:
+//!
+//! ```
+//! use refinement_types::{Predicate, int::u64::BitPosition};
+//!
+//! assert!(BitPosition::is_satisfied(&63));
+//!
+//! let error = BitPosition::check(&64).unwrap_err();
+//!
+//! assert_eq!(error.position, 64);
+//! ```
+//!
+//! Shifting by amounts refined with [`ValidShift`] never overflows:
+//!
+//! ```
+//! use refinement_types::{Predicate, Refinement, int::u32::ValidShift};
+//!
+//! assert!(ValidShift::is_satisfied(&31));
+//! assert!(!ValidShift::is_satisfied(&32));
+//!
+//! let shift = Refinement::<u32, ValidShift>::refine(31).unwrap();
+//!
+//! assert_eq!(1u32 << *shift, 1 << 31);
+//! ```
+//!
+//! [`BitPosition`]: u64::BitPosition
+//! [`ValidShift`]: u64::ValidShift
+//!
+//! Unsigned integer modules also provide the [`BitWidth`] predicate, which checks for
+//! powers of two up to `64`, and the [`ShiftAmount<B>`] predicate for shifts of `B` bits:
+//!
+//! ```
+//! use refinement_types::{
+//!     Predicate,
+//!     int::u8::{BitWidth, ShiftAmount},
+//! };
+//!
+//! for width in [8, 16, 32, 64] {
+//!     assert!(BitWidth::is_satisfied(&width));
+//! }
+//!
+//! assert!(!BitWidth::is_satisfied(&7));
+//! assert!(!BitWidth::is_satisfied(&128));
+//!
+//! assert!(ShiftAmount::<8>::is_satisfied(&7));
+//! assert!(!ShiftAmount::<8>::is_satisfied(&8));
+//! ```
+//!
+//! [`BitWidth`]: u8::BitWidth
+//! [`ShiftAmount<B>`]: u8::ShiftAmount
+//!
+//! # Alignment
+//!
+//! Each integer module provides the [`AlignedTo<A>`] predicate, which checks whether values
+//! are multiples of the power of two `A`:
+//!
+//! ```
+//! use refinement_types::{Predicate, int::usize::AlignedTo};
+//!
+//! for value in [0, 8, 16] {
+//!     assert!(AlignedTo::<8>::is_satisfied(&value));
+//! }
+//!
+//! let error = AlignedTo::<8>::check(&4).unwrap_err();
+//!
+//! assert_eq!(error.align, 8);
+//! ```
+//!
+//! Alignments that are not powers of two are rejected at compile time:
+//!
+//! ```compile_fail
+//! use refinement_types::{Predicate, int::usize::AlignedTo};
+//!
+//! let aligned = AlignedTo::<6>::is_satisfied(&12);
+//! ```
+//!
+//! [`AlignedTo<A>`]: usize::AlignedTo
+//!
+//! # Narrowing
+//!
+//! Each integer module provides the [`FitsIn<U>`] predicate, which checks whether values
+//! can be converted to `U` without loss:
+//!
+//! ```
+//! use refinement_types::{Predicate, int::i64::FitsIn};
+//!
+//! type FitsInI32 = FitsIn<i32>;
+//!
+//! let max = i64::from(i32::MAX);
+//! let min = i64::from(i32::MIN);
+//!
+//! assert!(FitsInI32::is_satisfied(&max));
+//! assert!(FitsInI32::is_satisfied(&min));
+//!
+//! let error = FitsInI32::check(&(max + 1)).unwrap_err();
+//!
+//! assert_eq!(error.value, max + 1);
+//! assert_eq!(error.target, "i32");
+//!
+//! assert!(!FitsInI32::is_satisfied(&(min - 1)));
+//! ```
+//!
+//! [`FitsIn<U>`]: i64::FitsIn
+//!
+//! # Digits and figurate numbers
+//!
+//! Unsigned integer modules provide the [`Triangular`] predicate, which checks
+//! whether values are equal to `n(n + 1) / 2` for some `n`:
+//!
+//! ```
+//! use refinement_types::{Predicate, int::u128::Triangular};
+//!
+//! for value in [0, 1, 3, 6, 10, 15] {
+//!     assert!(Triangular::is_satisfied(&value));
+//! }
+//!
+//! for value in [2, 4, 5] {
+//!     assert!(!Triangular::is_satisfied(&value));
+//! }
+//!
+//! // the largest triangular number that fits into `u128`
+//! let largest = 340_282_366_920_938_463_458_179_421_426_580_008_100;
+//!
+//! assert!(Triangular::is_satisfied(&largest));
+//! assert!(!Triangular::is_satisfied(&(largest - 1)));
+//! assert!(!Triangular::is_satisfied(&(largest + 1)));
+//! assert!(!Triangular::is_satisfied(&u128::MAX));
+//! ```
+//!
+//! [`Triangular`]: u128::Triangular
+//!
+//! Similarly, the [`AllSameDigits`] predicate checks whether all base-10 digits are the same:
+//!
+//! ```
+//! use refinement_types::{Predicate, int::u16::AllSameDigits};
+//!
+//! assert!(AllSameDigits::is_satisfied(&0));
+//! assert!(AllSameDigits::is_satisfied(&5));
+//! assert!(AllSameDigits::is_satisfied(&55));
+//! assert!(AllSameDigits::is_satisfied(&7777));
+//!
+//! assert!(!AllSameDigits::is_satisfied(&515));
+//! assert!(!AllSameDigits::is_satisfied(&10));
+//! ```
+//!
+//! [`AllSameDigits`]: u16::AllSameDigits
 
+pub mod arithmetic;
+pub mod divisible;
 pub(crate) mod macros;
+pub(crate) mod math;
+pub mod named;
+pub(crate) mod specific;
 
-macros::unsigned_module!(u8 => u8);
-macros::unsigned_module!(u16 => u16);
-macros::unsigned_module!(u32 => u32);
-macros::unsigned_module!(u64 => u64);
+pub use arithmetic::{ArithmeticError, RefinedInt};
+
+macros::unsigned_module!(u8 => u8 with specific);
+macros::unsigned_module!(u16 => u16 with specific);
+macros::unsigned_module!(u32 => u32 with specific);
+macros::unsigned_module!(u64 => u64 with specific);
 macros::unsigned_module!(u128 => u128);
 macros::unsigned_module!(usize => usize);
 
 macros::signed_module!(i8 => i8);
 macros::signed_module!(i16 => i16);
 macros::signed_module!(i32 => i32);
-macros::signed_module!(i64 => i64);
+macros::signed_module!(i64 => i64 with specific);
 macros::signed_module!(i128 => i128);
 macros::signed_module!(isize => isize);
@@ -0,0 +1,137 @@
+//! Predicates comparing integers against type-level integers.
+//!
+//! Unlike predicates in type-specific modules, which take bounds as const generics,
+//! these take bounds as [`TypeInt`] types, allowing bounds to be named and shared.
+//!
+//! # Examples
+//!
+//! ```
+//! use refinement_types::{Predicate, int::named::LessOrEqualTo, type_int};
+//!
+//! type_int!(MaxUsers = 1000);
+//!
+//! type UserCount = LessOrEqualTo<MaxUsers>;
+//!
+//! assert!(UserCount::is_satisfied(&1000_u16));
+//! assert!(UserCount::is_satisfied(&-1_i32));
+//!
+//! let error = UserCount::check(&1001_u64).unwrap_err();
+//!
+//! assert_eq!(error.other, 1000);
+//! ```
+
+use core::{cmp::Ordering, fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::{core::Predicate, type_int::TypeInt};
+
+/// Represents integers that can be compared with type-level integers.
+pub trait Integer: Copy {
+    /// Compares the value with the given [`i128`] value.
+    fn compare(self, other: i128) -> Ordering;
+}
+
+macro_rules! integer {
+    ($($int: ty),+ $(,)?) => {
+        $(
+            impl Integer for $int {
+                fn compare(self, other: i128) -> Ordering {
+                    i128::try_from(self).map_or(Ordering::Greater, |value| value.cmp(&other))
+                }
+            }
+        )+
+    };
+}
+
+integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+macro_rules! named {
+    ($name: ident [$code: ident] ($operation: tt)) => {
+        $crate::int::macros::import::paste! {
+            #[doc = concat!(
+                "Represents errors that occur when the provided value is ",
+                $crate::int::macros::human_inverse!($operation),
+                " [`other`](Self::other).",
+            )]
+            #[derive(Debug, Error)]
+            #[error(
+                "received value {inverse} {other}",
+                inverse = $crate::int::macros::string_inverse!($operation)
+            )]
+            #[cfg_attr(
+                feature = "diagnostics",
+                derive(Diagnostic),
+                diagnostic(
+                    code(int::named::$code),
+                    help(
+                        "make sure the value is {human} {other}",
+                        human = $crate::int::macros::human!($operation)
+                    )
+                )
+            )]
+            pub struct [< $name Error >] {
+                /// The other value (the `L`).
+                pub other: i128,
+            }
+
+            impl [< $name Error >] {
+                /// Constructs [`Self`].
+                pub const fn new(other: i128) -> Self {
+                    Self { other }
+                }
+            }
+
+            #[doc = concat!(
+                "Checks whether the given value is ",
+                $crate::int::macros::human!($operation),
+                " `L`."
+            )]
+            pub struct $name<L: TypeInt + ?Sized> {
+                other: PhantomData<L>,
+            }
+
+            impl<T: Integer, L: TypeInt + ?Sized> Predicate<T> for $name<L> {
+                type Error = [< $name Error >];
+
+                fn check(value: &T) -> Result<(), Self::Error> {
+                    if value.compare(L::VALUE) $operation Ordering::Equal {
+                        Ok(())
+                    } else {
+                        Err(Self::Error::new(L::VALUE))
+                    }
+                }
+
+                fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(
+                        formatter,
+                        "value {operation} {other}",
+                        operation = stringify!($operation),
+                        other = L::VALUE
+                    )
+                }
+
+                fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(
+                        formatter,
+                        "int::named::{code}<{other}>",
+                        code = stringify!($code),
+                        other = L::VALUE
+                    )
+                }
+            }
+        }
+    };
+}
+
+named!(LessThan [lt] (<));
+named!(LessOrEqualTo [le] (<=));
+named!(GreaterThan [gt] (>));
+named!(GreaterOrEqualTo [ge] (>=));
+named!(EqualTo [eq] (==));
+named!(NotEqualTo [ne] (!=));
@@ -0,0 +1,27 @@
+//! Predicates specific to [`i64`] values.
+
+use crate::int::i64::Closed;
+
+/// The maximum integer that can be represented exactly by JSON consumers
+/// using double-precision floats, that is, `2^53 - 1`.
+pub const MAX_SAFE_JSON_INTEGER: i64 = (1 << 53) - 1;
+
+/// The minimum integer that can be represented exactly by JSON consumers
+/// using double-precision floats, that is, `-(2^53 - 1)`.
+pub const MIN_SAFE_JSON_INTEGER: i64 = -MAX_SAFE_JSON_INTEGER;
+
+/// Checks whether the given value is within
+/// [[`MIN_SAFE_JSON_INTEGER`], [`MAX_SAFE_JSON_INTEGER`]].
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::i64::SafeJsonInteger};
+///
+/// assert!(SafeJsonInteger::is_satisfied(&9007199254740991));
+/// assert!(SafeJsonInteger::is_satisfied(&-9007199254740991));
+///
+/// assert!(!SafeJsonInteger::is_satisfied(&9007199254740992));
+/// assert!(!SafeJsonInteger::is_satisfied(&-9007199254740992));
+/// ```
+pub type SafeJsonInteger = Closed<MIN_SAFE_JSON_INTEGER, MAX_SAFE_JSON_INTEGER>;
@@ -0,0 +1,7 @@
+//! Predicates specific to some integer types.
+
+pub(crate) mod i64;
+pub(crate) mod u16;
+pub(crate) mod u32;
+pub(crate) mod u64;
+pub(crate) mod u8;
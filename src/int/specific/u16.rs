@@ -0,0 +1,34 @@
+//! Predicates specific to [`u16`] values.
+
+use crate::int::u16::Closed;
+
+/// Checks whether the given value is a valid HTTP status code, that is, within `[100, 599]`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::u16::HttpStatus};
+///
+/// assert!(HttpStatus::is_satisfied(&200));
+/// assert!(HttpStatus::is_satisfied(&404));
+/// assert!(HttpStatus::is_satisfied(&599));
+///
+/// assert!(!HttpStatus::is_satisfied(&600));
+/// assert!(!HttpStatus::is_satisfied(&99));
+/// ```
+pub type HttpStatus = Closed<100, 599>;
+
+/// Checks whether the given value is an informational HTTP status code (`1xx`).
+pub type HttpInformational = Closed<100, 199>;
+
+/// Checks whether the given value is a successful HTTP status code (`2xx`).
+pub type HttpSuccess = Closed<200, 299>;
+
+/// Checks whether the given value is a redirection HTTP status code (`3xx`).
+pub type HttpRedirection = Closed<300, 399>;
+
+/// Checks whether the given value is a client error HTTP status code (`4xx`).
+pub type HttpClientError = Closed<400, 499>;
+
+/// Checks whether the given value is a server error HTTP status code (`5xx`).
+pub type HttpServerError = Closed<500, 599>;
@@ -0,0 +1,144 @@
+//! Predicates specific to [`u32`] values.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::core::Predicate;
+
+/// Represents errors that occur when the value is not a valid Unicode scalar value.
+#[derive(Debug, Error)]
+#[error("received invalid code point {value:#X}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(int::u32::valid_code_point),
+        help("make sure the value is a valid Unicode scalar value")
+    )
+)]
+pub struct InvalidCodePointError {
+    /// The invalid value.
+    pub value: u32,
+}
+
+impl InvalidCodePointError {
+    /// Constructs [`Self`].
+    pub const fn new(value: u32) -> Self {
+        Self { value }
+    }
+}
+
+/// Checks whether the given value is a valid Unicode scalar value,
+/// that is, whether it can be converted to [`char`].
+///
+/// This excludes surrogates (`0xD800..=0xDFFF`) and values greater than `0x10FFFF`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::u32::ValidCodePoint};
+///
+/// assert!(ValidCodePoint::is_satisfied(&0x41));
+/// assert!(!ValidCodePoint::is_satisfied(&0xD800));
+/// assert!(!ValidCodePoint::is_satisfied(&0x110000));
+/// ```
+pub struct ValidCodePoint {
+    private: PhantomData<()>,
+}
+
+impl Predicate<u32> for ValidCodePoint {
+    type Error = InvalidCodePointError;
+
+    fn check(value: &u32) -> Result<(), Self::Error> {
+        if char::from_u32(*value).is_some() {
+            Ok(())
+        } else {
+            Err(Self::Error::new(*value))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("valid code point")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("u32::valid_code_point")
+    }
+}
+
+/// Represents errors that occur when the value is not a valid digit value in some base.
+#[derive(Debug, Error)]
+#[error("received value {value} that is not a digit in base {base}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(int::u32::digit_value),
+        help("make sure the value is less than {base}")
+    )
+)]
+pub struct DigitValueError {
+    /// The invalid value.
+    pub value: u32,
+    /// The base against which the check was performed (the `BASE`).
+    pub base: u32,
+}
+
+impl DigitValueError {
+    /// Constructs [`Self`].
+    pub const fn new(value: u32, base: u32) -> Self {
+        Self { value, base }
+    }
+}
+
+/// Checks whether the given value is a valid digit value in base `BASE`,
+/// that is, whether it is less than `BASE`.
+///
+/// Unlike [`Digit`], which checks characters, this predicate checks numeric values,
+/// for instance, ones obtained via [`char::to_digit`].
+///
+/// `BASE` must be in the `[2, 36]` range, which is checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::u32::DigitValue};
+///
+/// assert!((0..16).all(|value| DigitValue::<16>::is_satisfied(&value)));
+///
+/// let error = DigitValue::<16>::check(&16).unwrap_err();
+///
+/// assert_eq!(error.value, 16);
+/// assert_eq!(error.base, 16);
+/// ```
+///
+/// [`Digit`]: crate::char::ascii::Digit
+pub struct DigitValue<const BASE: u32> {
+    private: PhantomData<()>,
+}
+
+impl<const BASE: u32> Predicate<u32> for DigitValue<BASE> {
+    type Error = DigitValueError;
+
+    fn check(value: &u32) -> Result<(), Self::Error> {
+        const { assert!(BASE >= 2 && BASE <= 36, "expected base in `[2, 36]`") };
+
+        if *value < BASE {
+            Ok(())
+        } else {
+            Err(Self::Error::new(*value, BASE))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "digit value in base {BASE}")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "u32::digit_value<{BASE}>")
+    }
+}
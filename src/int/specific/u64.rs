@@ -0,0 +1,19 @@
+//! Predicates specific to [`u64`] values.
+
+use crate::int::u64::LessOrEqual;
+
+/// The maximum integer that can be represented exactly by JSON consumers
+/// using double-precision floats, that is, `2^53 - 1`.
+pub const MAX_SAFE_JSON_INTEGER: u64 = (1 << 53) - 1;
+
+/// Checks whether the given value does not exceed [`MAX_SAFE_JSON_INTEGER`].
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::u64::SafeJsonInteger};
+///
+/// assert!(SafeJsonInteger::is_satisfied(&9007199254740991));
+/// assert!(!SafeJsonInteger::is_satisfied(&9007199254740992));
+/// ```
+pub type SafeJsonInteger = LessOrEqual<MAX_SAFE_JSON_INTEGER>;
@@ -0,0 +1,260 @@
+//! Predicates specific to [`u8`] values.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::{core::Predicate, logic::True};
+
+/// Represents errors that occur when the byte is not ASCII.
+#[derive(Debug, Error)]
+#[error("received non-ascii byte {value:#04X}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(code(int::u8::ascii), help("make sure the byte is less than `0x80`"))
+)]
+pub struct AsciiByteError {
+    /// The invalid byte.
+    pub value: u8,
+}
+
+impl AsciiByteError {
+    /// Constructs [`Self`].
+    pub const fn new(value: u8) -> Self {
+        Self { value }
+    }
+}
+
+/// Checks whether the given byte is ASCII, that is, less than `0x80`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::u8::AsciiByte};
+///
+/// assert!(AsciiByte::is_satisfied(&0x41));
+/// assert!(AsciiByte::is_satisfied(&0x09));
+/// assert!(!AsciiByte::is_satisfied(&0x80));
+/// ```
+pub struct AsciiByte {
+    private: PhantomData<()>,
+}
+
+impl Predicate<u8> for AsciiByte {
+    type Error = AsciiByteError;
+
+    fn check(value: &u8) -> Result<(), Self::Error> {
+        if value.is_ascii() {
+            Ok(())
+        } else {
+            Err(Self::Error::new(*value))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("ascii byte")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("u8::ascii")
+    }
+}
+
+/// Represents errors that occur when the byte is not printable ASCII.
+#[derive(Debug, Error)]
+#[error("received non-printable byte {value:#04X}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(int::u8::ascii_printable),
+        help("make sure the byte is within `0x20..=0x7E`")
+    )
+)]
+pub struct AsciiPrintableByteError {
+    /// The invalid byte.
+    pub value: u8,
+}
+
+impl AsciiPrintableByteError {
+    /// Constructs [`Self`].
+    pub const fn new(value: u8) -> Self {
+        Self { value }
+    }
+}
+
+/// Checks whether the given byte is printable ASCII, that is, within `0x20..=0x7E`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::u8::AsciiPrintableByte};
+///
+/// assert!(AsciiPrintableByte::is_satisfied(&0x41));
+/// assert!(!AsciiPrintableByte::is_satisfied(&0x09));
+/// assert!(!AsciiPrintableByte::is_satisfied(&0x80));
+/// ```
+pub struct AsciiPrintableByte {
+    private: PhantomData<()>,
+}
+
+impl Predicate<u8> for AsciiPrintableByte {
+    type Error = AsciiPrintableByteError;
+
+    fn check(value: &u8) -> Result<(), Self::Error> {
+        if matches!(value, 0x20..=0x7E) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(*value))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("printable ascii byte")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("u8::ascii_printable")
+    }
+}
+
+/// Represents errors that occur when the byte is not an ASCII control character.
+#[derive(Debug, Error)]
+#[error("received non-control byte {value:#04X}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(int::u8::ascii_control),
+        help("make sure the byte is either within `0x00..=0x1F` or `0x7F`")
+    )
+)]
+pub struct AsciiControlByteError {
+    /// The invalid byte.
+    pub value: u8,
+}
+
+impl AsciiControlByteError {
+    /// Constructs [`Self`].
+    pub const fn new(value: u8) -> Self {
+        Self { value }
+    }
+}
+
+/// Checks whether the given byte is an ASCII control character,
+/// that is, either within `0x00..=0x1F` or `0x7F`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::u8::AsciiControlByte};
+///
+/// assert!(AsciiControlByte::is_satisfied(&0x09));
+/// assert!(!AsciiControlByte::is_satisfied(&0x41));
+/// assert!(!AsciiControlByte::is_satisfied(&0x80));
+/// ```
+pub struct AsciiControlByte {
+    private: PhantomData<()>,
+}
+
+impl Predicate<u8> for AsciiControlByte {
+    type Error = AsciiControlByteError;
+
+    fn check(value: &u8) -> Result<(), Self::Error> {
+        if value.is_ascii_control() {
+            Ok(())
+        } else {
+            Err(Self::Error::new(*value))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("ascii control byte")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("u8::ascii_control")
+    }
+}
+
+/// The maximum 16-color ANSI code.
+pub const MAX_ANSI_16: u8 = 15;
+
+/// Represents errors that occur when the code is not a valid 16-color ANSI code.
+#[derive(Debug, Error)]
+#[error("received invalid 16-color ANSI code {value}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(code(int::u8::ansi_16), help("make sure the code is at most 15"))
+)]
+pub struct Ansi16Error {
+    /// The invalid code.
+    pub value: u8,
+}
+
+impl Ansi16Error {
+    /// Constructs [`Self`].
+    pub const fn new(value: u8) -> Self {
+        Self { value }
+    }
+}
+
+/// Checks whether the given code is a valid 16-color ANSI code, that is, is at most `15`.
+///
+/// Codes `0..=7` are standard colors, and `8..=15` are their bright variants.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::u8::Ansi16};
+///
+/// assert!(Ansi16::is_satisfied(&0));
+/// assert!(Ansi16::is_satisfied(&15));
+///
+/// let error = Ansi16::check(&16).unwrap_err();
+///
+/// assert_eq!(error.to_string(), "received invalid 16-color ANSI code 16");
+/// ```
+pub struct Ansi16 {
+    private: PhantomData<()>,
+}
+
+impl Predicate<u8> for Ansi16 {
+    type Error = Ansi16Error;
+
+    fn check(value: &u8) -> Result<(), Self::Error> {
+        if *value <= MAX_ANSI_16 {
+            Ok(())
+        } else {
+            Err(Self::Error::new(*value))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("16-color ANSI code")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("u8::ansi_16")
+    }
+}
+
+/// Checks whether the given code is a valid 256-color ANSI code.
+///
+/// Since every [`u8`] value is a valid 256-color code, this predicate is always satisfied;
+/// it exists to document the intent in types.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::u8::Ansi256};
+///
+/// assert!(Ansi256::is_satisfied(&16));
+/// assert!(Ansi256::is_satisfied(&255));
+/// ```
+pub type Ansi256 = True;
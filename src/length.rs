@@ -12,12 +12,30 @@ use crate::{
     logic::{And, Not},
 };
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::{core::Refinement, type_str::TypeStr};
+
 /// Represents types that have length defined for their values.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Refinement, length::Greater};
+///
+/// let bytes = Refinement::<[u8; 4], Greater<2>>::refine([1, 2, 3, 4]).unwrap();
+///
+/// assert_eq!(*bytes, [1, 2, 3, 4]);
+///
+/// assert!(Refinement::<[u8; 2], Greater<2>>::refine([1, 2]).is_err());
+/// ```
 pub trait HasLength {
     /// Returns the value length.
     fn length(&self) -> usize;
 }
 
+#[cfg(feature = "derive")]
+pub use refinement_types_derive::HasLength;
+
 /// Represents errors that occur when the provided value has
 /// length greater than or equal to some bound.
 #[derive(Debug, Error)]
@@ -374,6 +392,343 @@ pub type Even = Divisible<2>;
 /// Checks whether the given value length is odd.
 pub type Odd = Not<Even>;
 
+/// Represents errors that occur when the provided value has length that is not a power of two.
+#[derive(Debug, Error)]
+#[error("received value with length {length} that is not a power of two")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(length::power_of_two),
+        help("make sure the length is a power of two")
+    )
+)]
+pub struct PowerOfTwoLengthError {
+    /// The received length.
+    pub length: usize,
+}
+
+impl PowerOfTwoLengthError {
+    /// Constructs [`Self`].
+    pub const fn new(length: usize) -> Self {
+        Self { length }
+    }
+}
+
+/// Checks whether the given value length is a power of two.
+///
+/// Note that zero is not a power of two, therefore empty values are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, length::PowerOfTwoLength};
+///
+/// assert!(PowerOfTwoLength::is_satisfied(&[0u8; 8][..]));
+/// assert!(!PowerOfTwoLength::is_satisfied(&[0u8; 6][..]));
+/// assert!(!PowerOfTwoLength::is_satisfied(&[0u8; 0][..]));
+/// ```
+pub struct PowerOfTwoLength {
+    private: PhantomData<()>,
+}
+
+impl<T: HasLength + ?Sized> Predicate<T> for PowerOfTwoLength {
+    type Error = PowerOfTwoLengthError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let length = value.length();
+
+        if length.is_power_of_two() {
+            Ok(())
+        } else {
+            Err(Self::Error::new(length))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("length that is a power of two")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("length::power_of_two")
+    }
+}
+
+/// Represents errors that occur when the provided value is empty or has length greater than
+/// some bound.
+#[derive(Debug, Error)]
+#[error("received value with length {length}, expected between 1 and {max} items")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(length::bounded_non_empty),
+        help("make sure the value has between 1 and {max} items")
+    )
+)]
+pub struct BoundedNonEmptyError {
+    /// The received length.
+    pub length: usize,
+    /// The maximum length (the `MAX`).
+    pub max: usize,
+}
+
+impl BoundedNonEmptyError {
+    /// Constructs [`Self`].
+    pub const fn new(length: usize, max: usize) -> Self {
+        Self { length, max }
+    }
+}
+
+/// Checks whether the given value is non-empty and has length less than or equal to `MAX`.
+///
+/// This is equivalent to [`Closed<1, MAX>`], except that it produces single concise errors.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, length::BoundedNonEmpty};
+///
+/// assert!(BoundedNonEmpty::<3>::is_satisfied(&[1, 2][..]));
+/// assert!(BoundedNonEmpty::<3>::is_satisfied(&[1, 2, 3][..]));
+///
+/// let error = BoundedNonEmpty::<3>::check(&[0; 0][..]).unwrap_err();
+///
+/// assert_eq!(
+///     error.to_string(),
+///     "received value with length 0, expected between 1 and 3 items"
+/// );
+///
+/// let error = BoundedNonEmpty::<3>::check(&[1, 2, 3, 4][..]).unwrap_err();
+///
+/// assert_eq!(error.length, 4);
+/// ```
+pub struct BoundedNonEmpty<const MAX: usize> {
+    private: PhantomData<()>,
+}
+
+impl<const MAX: usize, T: HasLength + ?Sized> Predicate<T> for BoundedNonEmpty<MAX> {
+    type Error = BoundedNonEmptyError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let length = value.length();
+
+        if (1..=MAX).contains(&length) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(length, MAX))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "value with length in [1, {MAX}]")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "length::bounded_non_empty<{MAX}>")
+    }
+}
+
+/// Checks whether the given values have the same length.
+pub fn same_length<A: HasLength + ?Sized, B: HasLength + ?Sized>(left: &A, right: &B) -> bool {
+    left.length() == right.length()
+}
+
+/// Represents errors that occur when the provided pair contains values of different lengths.
+#[derive(Debug, Error)]
+#[error("received values with lengths {left} != {right}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(code(length::same), help("make sure both values have the same length"))
+)]
+pub struct SameLengthError {
+    /// The length of the left value.
+    pub left: usize,
+    /// The length of the right value.
+    pub right: usize,
+}
+
+impl SameLengthError {
+    /// Constructs [`Self`].
+    pub const fn new(left: usize, right: usize) -> Self {
+        Self { left, right }
+    }
+}
+
+/// Checks whether both values of the given pair have the same length.
+///
+/// This is useful for cross-field validation, for instance, of parallel arrays.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Refinement, length::SameLength};
+///
+/// let names: &[&str] = &["nekit", "xenon"];
+/// let ages: &[u8] = &[13, 42];
+///
+/// assert!(Refinement::<_, SameLength>::refine((names, ages)).is_ok());
+///
+/// let error = Refinement::<_, SameLength>::refine((names, &ages[1..])).unwrap_err();
+///
+/// assert_eq!((error.error.left, error.error.right), (2, 1));
+/// ```
+pub struct SameLength {
+    private: PhantomData<()>,
+}
+
+impl<A: HasLength, B: HasLength> Predicate<(A, B)> for SameLength {
+    type Error = SameLengthError;
+
+    fn check((left, right): &(A, B)) -> Result<(), Self::Error> {
+        if same_length(left, right) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(left.length(), right.length()))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("values with the same length")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("length::same")
+    }
+}
+
+/// Represents types that have character length defined for their values,
+/// that is, the number of Unicode scalar values (as opposed to bytes).
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     length::{CharEqual, CharLessOrEqual, HasCharLength, HasLength, LessOrEqual},
+/// };
+///
+/// assert_eq!("héllo".length(), 6);
+/// assert_eq!("héllo".char_length(), 5);
+///
+/// assert_eq!("🦀🦀".length(), 8);
+/// assert_eq!("🦀🦀".char_length(), 2);
+///
+/// assert!(CharLessOrEqual::<5>::is_satisfied("héllo"));
+/// assert!(!LessOrEqual::<5>::is_satisfied("héllo"));
+///
+/// assert!(CharEqual::<2>::is_satisfied("🦀🦀"));
+/// ```
+pub trait HasCharLength {
+    /// Returns the value character length.
+    fn char_length(&self) -> usize;
+}
+
+macro_rules! char_length {
+    (
+        $name: ident [$error: ident] ($operation: tt $code: ident)
+        Message = $message: literal,
+        Human = $human: literal $(,)?
+    ) => {
+        #[doc = concat!(
+            "Represents errors that occur when the provided value has character length `",
+            $message,
+            "` some bound."
+        )]
+        #[derive(Debug, Error)]
+        #[error("received value with char length {inverse} {other}", inverse = $message)]
+        #[cfg_attr(
+            feature = "diagnostics",
+            derive(Diagnostic),
+            diagnostic(
+                code(length::$code),
+                help("make sure the char length is {human} {other}", human = $human)
+            )
+        )]
+        pub struct $error {
+            /// The character length against which the check was performed (the `N`).
+            pub other: usize,
+        }
+
+        impl $error {
+            /// Constructs [`Self`].
+            pub const fn new(other: usize) -> Self {
+                Self { other }
+            }
+        }
+
+        #[doc = concat!(
+            "Checks whether the given value has character length `",
+            stringify!($operation),
+            " N`."
+        )]
+        pub struct $name<const N: usize> {
+            private: PhantomData<()>,
+        }
+
+        impl<const N: usize, T: HasCharLength + ?Sized> Predicate<T> for $name<N> {
+            type Error = $error;
+
+            fn check(value: &T) -> Result<(), Self::Error> {
+                if value.char_length() $operation N {
+                    Ok(())
+                } else {
+                    Err(Self::Error::new(N))
+                }
+            }
+
+            fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "value with char length {} {N}",
+                    stringify!($operation)
+                )
+            }
+
+            fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "length::{}<{N}>", stringify!($code))
+            }
+        }
+    };
+}
+
+char_length! {
+    CharLess [CharLessError] (< char_lt)
+    Message = ">=",
+    Human = "less than",
+}
+
+char_length! {
+    CharLessOrEqual [CharLessOrEqualError] (<= char_le)
+    Message = ">",
+    Human = "less than or equal to",
+}
+
+char_length! {
+    CharGreater [CharGreaterError] (> char_gt)
+    Message = "<=",
+    Human = "greater than",
+}
+
+char_length! {
+    CharGreaterOrEqual [CharGreaterOrEqualError] (>= char_ge)
+    Message = "<",
+    Human = "greater than or equal to",
+}
+
+char_length! {
+    CharEqual [CharEqualError] (== char_eq)
+    Message = "!=",
+    Human = "equal to",
+}
+
+char_length! {
+    CharNotEqual [CharNotEqualError] (!= char_ne)
+    Message = "==",
+    Human = "not equal to",
+}
+
 // core
 
 impl HasLength for str {
@@ -388,6 +743,12 @@ impl<T> HasLength for [T] {
     }
 }
 
+impl<T, const N: usize> HasLength for [T; N] {
+    fn length(&self) -> usize {
+        N
+    }
+}
+
 impl<T: HasLength + ?Sized> HasLength for &T {
     fn length(&self) -> usize {
         T::length(self)
@@ -420,6 +781,57 @@ impl<T> HasLength for Vec<T> {
     }
 }
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T, const N: usize, C: TypeStr + ?Sized> Refinement<Vec<T>, GreaterOrEqual<N>, C> {
+    /// Returns the first `N` elements of the vector.
+    ///
+    /// This is infallible, as the vector is known to have at least `N` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{Refinement, length::GreaterOrEqual};
+    ///
+    /// type AtLeastThree = Refinement<Vec<u8>, GreaterOrEqual<3>>;
+    ///
+    /// let values = AtLeastThree::refine(vec![1, 2, 3, 4]).unwrap();
+    ///
+    /// assert_eq!(values.first_n(), [1, 2, 3]);
+    /// ```
+    pub fn first_n(&self) -> &[T] {
+        // SAFETY: the vector has at least `N` elements
+        unsafe { self.get().get_unchecked(..N) }
+    }
+
+    /// Returns the first element and the rest of the vector.
+    ///
+    /// This is infallible, as the vector is known to have at least `N` elements,
+    /// and `N` is checked to be non-zero at compile time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{Refinement, length::GreaterOrEqual};
+    ///
+    /// type AtLeastThree = Refinement<Vec<u8>, GreaterOrEqual<3>>;
+    ///
+    /// let values = AtLeastThree::refine(vec![1, 2, 3]).unwrap();
+    ///
+    /// let (first, rest) = values.split_first_refined();
+    ///
+    /// assert_eq!(*first, 1);
+    /// assert_eq!(rest, [2, 3]);
+    /// ```
+    pub fn split_first_refined(&self) -> (&T, &[T]) {
+        const { assert!(N > 0, "expected non-zero length bound") };
+
+        let values = self.get();
+
+        // SAFETY: the vector has at least `N > 0` elements
+        unsafe { (values.get_unchecked(0), values.get_unchecked(1..)) }
+    }
+}
+
 // clone-on-write
 
 #[cfg(feature = "alloc")]
@@ -562,3 +974,31 @@ impl HasLength for PathBuf {
         self.as_os_str().length()
     }
 }
+
+// char lengths
+
+impl HasCharLength for str {
+    fn char_length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl<T: HasCharLength + ?Sized> HasCharLength for &T {
+    fn char_length(&self) -> usize {
+        T::char_length(self)
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl HasCharLength for String {
+    fn char_length(&self) -> usize {
+        self.as_str().char_length()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl HasCharLength for Cow<'_, str> {
+    fn char_length(&self) -> usize {
+        self.as_ref().char_length()
+    }
+}
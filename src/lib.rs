@@ -17,24 +17,48 @@ extern crate alloc;
 
 pub mod char;
 pub mod core;
+#[macro_use]
+pub mod duration;
+pub mod dynamic;
 pub mod empty;
+pub mod float;
 pub mod int;
 pub mod length;
 #[macro_use]
 pub mod logic;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod map;
+pub mod project;
+pub mod slice;
 pub mod static_str;
 pub mod str;
 #[macro_use]
+pub mod type_bytes;
+#[macro_use]
+pub mod type_float;
+#[macro_use]
+pub mod type_int;
+#[macro_use]
 pub mod type_str;
 
 #[cfg(feature = "regex")]
 #[macro_use]
 pub mod type_regex;
 
+#[cfg(feature = "heapless")]
+pub mod errors;
+
 pub use core::{Error, ErrorCore, Predicate, Refinement};
 
+#[cfg(feature = "derive")]
+pub use core::predicate;
+
+pub use duration::TypeDuration;
 pub use static_str::StaticStr;
+pub use type_bytes::TypeBytes;
+pub use type_float::TypeFloat;
+pub use type_int::TypeInt;
 pub use type_str::TypeStr;
 
 #[cfg(feature = "regex")]
-pub use type_regex::{Regex, StaticRegex, TypeRegex};
+pub use type_regex::{Regex, RegexSet, StaticRegex, StaticRegexSet, TypeRegex, TypeRegexSet};
@@ -8,10 +8,14 @@ use miette::Diagnostic;
 use thiserror::Error;
 
 use crate::{
-    core::{ErrorCore, Predicate},
+    core::{Error, ErrorCore, Predicate, Refinement},
     static_str::StaticStr,
+    type_str::TypeStr,
 };
 
+#[cfg(feature = "heapless")]
+use crate::errors::Errors;
+
 /// Represents predicates that are always satisfied.
 pub struct True {
     private: PhantomData<()>,
@@ -134,6 +138,29 @@ impl<E: Diagnostic + 'static, F: Diagnostic + 'static> Diagnostic for EitherErro
 }
 
 /// Represents predicates that are satisfied when both `P` and `Q` are satisfied.
+///
+/// Like other logical predicates, this type implements [`Clone`], [`Copy`], [`Default`]
+/// and [`Debug`] regardless of `P` and `Q`, so it can be used in structures deriving these.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     int::u8,
+///     logic::And,
+/// };
+///
+/// #[derive(Debug, Default)]
+/// struct Checked {
+///     predicate: And<u8::Greater<13>, u8::Less<42>>,
+/// }
+///
+/// let checked = Checked::default();
+///
+/// assert_eq!(format!("{checked:?}"), "Checked { predicate: And }");
+/// ```
+///
+/// [`Debug`]: fmt::Debug
 pub struct And<P: ?Sized, Q: ?Sized> {
     left: PhantomData<P>,
     right: PhantomData<Q>,
@@ -230,6 +257,93 @@ impl<T: ?Sized, P: Predicate<T> + ?Sized, Q: Predicate<T> + ?Sized> Predicate<T>
     }
 }
 
+/// Represents values of either `L` or `R` types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Either<L, R> {
+    /// Left value.
+    Left(L),
+    /// Right value.
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Checks whether the value is [`Self::Left`].
+    pub const fn is_left(&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    /// Checks whether the value is [`Self::Right`].
+    pub const fn is_right(&self) -> bool {
+        matches!(self, Self::Right(_))
+    }
+
+    /// Returns the left value, if any.
+    pub fn left(self) -> Option<L> {
+        match self {
+            Self::Left(left) => Some(left),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// Returns the right value, if any.
+    pub fn right(self) -> Option<R> {
+        match self {
+            Self::Left(_) => None,
+            Self::Right(right) => Some(right),
+        }
+    }
+}
+
+impl<T, P: Predicate<T> + ?Sized, Q: Predicate<T> + ?Sized, C: TypeStr + ?Sized>
+    Refinement<T, Or<P, Q>, C>
+{
+    /// Refines the given value, tagging it with the predicate that validated it.
+    ///
+    /// Just like [`Or`], `P` is checked first, and `Q` is only checked if `P` fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`struct@Error`] if the value satisfies neither of the predicates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{
+    ///     Refinement,
+    ///     int::u8,
+    ///     logic::{Either, Or},
+    /// };
+    ///
+    /// type Extreme = Refinement<u8, Or<u8::Less<10>, u8::Greater<100>>>;
+    ///
+    /// let tagged = Extreme::tagged_refine(150).unwrap();
+    ///
+    /// assert!(tagged.is_right());
+    ///
+    /// let tagged = Extreme::tagged_refine(5).unwrap();
+    ///
+    /// assert!(matches!(tagged, Either::Left(refined) if *refined == 5));
+    ///
+    /// assert!(Extreme::tagged_refine(42).is_err());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn tagged_refine(
+        value: T,
+    ) -> Result<Either<Refinement<T, P, C>, Refinement<T, Q, C>>, Error<T, Or<P, Q>, C>> {
+        let left = match P::check(&value) {
+            // SAFETY: the value satisfies `P` if the check is successful
+            Ok(()) => return Ok(Either::Left(unsafe { Refinement::unchecked(value) })),
+            Err(error) => error,
+        };
+
+        match Q::check(&value) {
+            // SAFETY: the value satisfies `Q` if the check is successful
+            Ok(()) => Ok(Either::Right(unsafe { Refinement::unchecked(value) })),
+            Err(right) => Err(Error::new(value, BothError::new(left, right))),
+        }
+    }
+}
+
 /// Represents errors returned by [`Not`].
 #[derive(Debug, Error, Default)]
 #[error("negated error")]
@@ -347,6 +461,279 @@ impl<T: ?Sized, P: Predicate<T> + ?Sized, Q: Predicate<T> + ?Sized> Predicate<T>
     }
 }
 
+/// Represents [`expect`] functions of predicates.
+///
+/// [`expect`]: Predicate::expect
+pub type Expect = fn(&mut fmt::Formatter<'_>) -> fmt::Result;
+
+/// Represents lists of predicates, implemented for tuples of up to twelve predicates.
+pub trait Predicates<T: ?Sized> {
+    /// Counts the predicates that the given value satisfies.
+    fn count(value: &T) -> usize;
+
+    /// Formats the expectations of the predicates, separated by commas.
+    ///
+    /// # Errors
+    ///
+    /// These can rarely occur, but any [`fmt::Error`] values are simply propagated.
+    fn expect_all(formatter: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Formats the expectation codes of the predicates, separated by commas.
+    ///
+    /// # Errors
+    ///
+    /// These can rarely occur, but any [`fmt::Error`] values are simply propagated.
+    fn expect_code_all(formatter: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Calls `visit` with the index and the [`expect`] function of each predicate
+    /// that the given value does not satisfy.
+    ///
+    /// [`expect`]: Predicate::expect
+    fn visit_unsatisfied<V: FnMut(usize, Expect)>(value: &T, visit: V);
+}
+
+macro_rules! predicates {
+    ($first: ident $(, $rest: ident)* $(,)?) => {
+        impl<T: ?Sized, $first: Predicate<T>, $($rest: Predicate<T>),*> Predicates<T>
+            for ($first, $($rest,)*)
+        {
+            fn count(value: &T) -> usize {
+                usize::from($first::is_satisfied(value))
+                    $(+ usize::from($rest::is_satisfied(value)))*
+            }
+
+            fn expect_all(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "({})", $first::expected())?;
+
+                $(
+                    write!(formatter, ", ({})", $rest::expected())?;
+                )*
+
+                Ok(())
+            }
+
+            fn expect_code_all(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "{}", $first::expected_code())?;
+
+                $(
+                    write!(formatter, ", {}", $rest::expected_code())?;
+                )*
+
+                Ok(())
+            }
+
+            fn visit_unsatisfied<V: FnMut(usize, Expect)>(value: &T, mut visit: V) {
+                let index = 0;
+
+                if !$first::is_satisfied(value) {
+                    visit(index, $first::expect);
+                }
+
+                $(
+                    let index = index + 1;
+
+                    if !$rest::is_satisfied(value) {
+                        visit(index, $rest::expect);
+                    }
+                )*
+            }
+        }
+
+        predicates!($($rest),*);
+    };
+
+    () => {};
+}
+
+predicates!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// Represents errors returned by [`ExactlyOne`].
+#[derive(Debug, Error)]
+#[error("expected exactly one predicate to be satisfied, got {satisfied}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(logic::exactly_one),
+        help("make sure exactly one predicate is satisfied")
+    )
+)]
+pub struct ExactlyOneError {
+    /// The number of predicates satisfied.
+    pub satisfied: usize,
+}
+
+impl ExactlyOneError {
+    /// Constructs [`Self`].
+    pub const fn new(satisfied: usize) -> Self {
+        Self { satisfied }
+    }
+}
+
+/// Represents predicates that are satisfied when exactly one of the predicates in `L` is.
+///
+/// This generalizes [`Xor`] to any number of predicates; see [`exactly_one!`].
+///
+/// [`exactly_one!`]: crate::exactly_one
+pub struct ExactlyOne<L: ?Sized> {
+    predicates: PhantomData<L>,
+}
+
+impl<T: ?Sized, L: Predicates<T> + ?Sized> Predicate<T> for ExactlyOne<L> {
+    type Error = ExactlyOneError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let satisfied = L::count(value);
+
+        if satisfied == 1 {
+            Ok(())
+        } else {
+            Err(Self::Error::new(satisfied))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("exactly one of ")?;
+
+        L::expect_all(formatter)
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("exactly_one<")?;
+
+        L::expect_code_all(formatter)?;
+
+        formatter.write_str(">")
+    }
+}
+
+/// Displays expectations given their [`Expect`] functions.
+#[cfg(feature = "heapless")]
+struct Expectation(Expect);
+
+#[cfg(feature = "heapless")]
+impl fmt::Display for Expectation {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.0)(formatter)
+    }
+}
+
+/// Represents errors that occur when some predicate in the list is not satisfied.
+#[cfg(feature = "heapless")]
+#[derive(Debug)]
+pub struct UnsatisfiedError {
+    /// The index of the predicate in the list.
+    pub index: usize,
+    /// The [`expect`] function of the predicate.
+    ///
+    /// [`expect`]: Predicate::expect
+    pub expect: Expect,
+}
+
+#[cfg(feature = "heapless")]
+impl UnsatisfiedError {
+    /// Constructs [`Self`].
+    pub const fn new(index: usize, expect: Expect) -> Self {
+        Self { index, expect }
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl fmt::Display for UnsatisfiedError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "expected {expectation} (predicate at index {index})",
+            expectation = Expectation(self.expect),
+            index = self.index
+        )
+    }
+}
+
+#[cfg(feature = "heapless")]
+impl ErrorCore for UnsatisfiedError {}
+
+#[cfg(all(feature = "heapless", feature = "diagnostics"))]
+impl Diagnostic for UnsatisfiedError {
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("logic::unsatisfied"))
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new(format!(
+            "make sure the value is {expectation}",
+            expectation = Expectation(self.expect)
+        )))
+    }
+}
+
+/// Represents predicates that are satisfied when all of the predicates in `L` are,
+/// collecting errors of up to `N` unsatisfied predicates.
+///
+/// Unlike [`And`], this predicate does not stop on the first unsatisfied predicate,
+/// and does not require allocation to report all of them.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::u8, logic::All};
+///
+/// type Checked = All<(u8::Greater<13>, u8::Less<42>, u8::Divisible<2>), 2>;
+///
+/// assert!(Checked::is_satisfied(&34));
+///
+/// let errors = Checked::check(&7).unwrap_err();
+///
+/// assert_eq!(errors.total(), 2);
+/// assert_eq!(errors.as_slice()[0].index, 0);
+/// assert_eq!(errors.as_slice()[1].index, 2);
+///
+/// let errors = Checked::check(&43).unwrap_err();
+///
+/// assert_eq!(
+///     errors.to_string(),
+///     "2 errors occurred: expected u8 < 42 (predicate at index 1); \
+///     expected u8 % 2 == 0 (predicate at index 2)"
+/// );
+/// ```
+#[cfg(feature = "heapless")]
+pub struct All<L: ?Sized, const N: usize> {
+    predicates: PhantomData<L>,
+}
+
+#[cfg(feature = "heapless")]
+impl<T: ?Sized, L: Predicates<T> + ?Sized, const N: usize> Predicate<T> for All<L, N> {
+    type Error = Errors<UnsatisfiedError, N>;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let mut errors = Self::Error::new();
+
+        L::visit_unsatisfied(value, |index, expect| {
+            errors.push(UnsatisfiedError::new(index, expect));
+        });
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("all of ")?;
+
+        L::expect_all(formatter)
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("all<")?;
+
+        L::expect_code_all(formatter)?;
+
+        write!(formatter, ", {N}>")
+    }
+}
+
 /// Composes [`Not`] and [`And`].
 pub type Nand<P, Q> = Not<And<P, Q>>;
 
@@ -359,6 +746,629 @@ pub type Xnor<P, Q> = Not<Xor<P, Q>>;
 /// Represents predicates that are satisfied when `P` implies `Q`.
 pub type Imply<P, Q> = Or<Not<P>, Q>;
 
+/// Represents which predicates are satisfied, as reported by [`NeitherNorError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Satisfied {
+    /// The first predicate is satisfied.
+    First,
+    /// The second predicate is satisfied.
+    Second,
+    /// Both predicates are satisfied.
+    Both,
+}
+
+/// Represents errors returned by [`NeitherNor`].
+pub struct NeitherNorError<T: ?Sized, P: ?Sized, Q: ?Sized> {
+    /// The predicates that are satisfied.
+    pub satisfied: Satisfied,
+    value: PhantomData<T>,
+    left: PhantomData<P>,
+    right: PhantomData<Q>,
+}
+
+impl<T: ?Sized, P: ?Sized, Q: ?Sized> NeitherNorError<T, P, Q> {
+    /// Constructs [`Self`].
+    pub const fn new(satisfied: Satisfied) -> Self {
+        Self {
+            satisfied,
+            value: PhantomData,
+            left: PhantomData,
+            right: PhantomData,
+        }
+    }
+
+    /// Constructs [`Self`] with the first predicate satisfied.
+    pub const fn first() -> Self {
+        Self::new(Satisfied::First)
+    }
+
+    /// Constructs [`Self`] with the second predicate satisfied.
+    pub const fn second() -> Self {
+        Self::new(Satisfied::Second)
+    }
+
+    /// Constructs [`Self`] with both predicates satisfied.
+    pub const fn both() -> Self {
+        Self::new(Satisfied::Both)
+    }
+}
+
+impl<T: ?Sized, P: ?Sized, Q: ?Sized> fmt::Debug for NeitherNorError<T, P, Q> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("NeitherNorError")
+            .field("satisfied", &self.satisfied)
+            .finish()
+    }
+}
+
+impl<T: ?Sized, P: Predicate<T> + ?Sized, Q: Predicate<T> + ?Sized> fmt::Display
+    for NeitherNorError<T, P, Q>
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "expected neither ({}) nor ({}), ",
+            P::expected(),
+            Q::expected()
+        )?;
+
+        match self.satisfied {
+            Satisfied::First => write!(formatter, "but ({}) is satisfied", P::expected()),
+            Satisfied::Second => write!(formatter, "but ({}) is satisfied", Q::expected()),
+            Satisfied::Both => formatter.write_str("but both are satisfied"),
+        }
+    }
+}
+
+impl<T: ?Sized, P: Predicate<T> + ?Sized, Q: Predicate<T> + ?Sized> ErrorCore
+    for NeitherNorError<T, P, Q>
+{
+}
+
+#[cfg(feature = "diagnostics")]
+impl<T: ?Sized, P: Predicate<T> + ?Sized, Q: Predicate<T> + ?Sized> Diagnostic
+    for NeitherNorError<T, P, Q>
+{
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("logic::neither_nor"))
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        let help = match self.satisfied {
+            Satisfied::First => format!("make sure ({}) is not satisfied", P::expected()),
+            Satisfied::Second => format!("make sure ({}) is not satisfied", Q::expected()),
+            Satisfied::Both => format!(
+                "make sure neither ({}) nor ({}) is satisfied",
+                P::expected(),
+                Q::expected()
+            ),
+        };
+
+        Some(Box::new(help))
+    }
+}
+
+/// Represents predicates that are satisfied when neither `P` nor `Q` are satisfied.
+///
+/// This is equivalent to [`Nor`], except that errors tell which predicates are satisfied.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     int::u8,
+///     logic::{NeitherNor, Satisfied},
+/// };
+///
+/// type Middle = NeitherNor<u8::Less<13>, u8::Greater<42>>;
+///
+/// assert!(Middle::is_satisfied(&25));
+///
+/// let error = Middle::check(&69).unwrap_err();
+///
+/// assert_eq!(error.satisfied, Satisfied::Second);
+///
+/// assert_eq!(
+///     error.to_string(),
+///     "expected neither (u8 < 13) nor (u8 > 42), but (u8 > 42) is satisfied"
+/// );
+/// ```
+pub struct NeitherNor<P: ?Sized, Q: ?Sized> {
+    left: PhantomData<P>,
+    right: PhantomData<Q>,
+}
+
+impl<T: ?Sized, P: Predicate<T> + ?Sized, Q: Predicate<T> + ?Sized> Predicate<T>
+    for NeitherNor<P, Q>
+{
+    type Error = NeitherNorError<T, P, Q>;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        match (P::is_satisfied(value), Q::is_satisfied(value)) {
+            (false, false) => Ok(()),
+            (true, false) => Err(Self::Error::first()),
+            (false, true) => Err(Self::Error::second()),
+            (true, true) => Err(Self::Error::both()),
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "neither ({}) nor ({})",
+            P::expected(),
+            Q::expected()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "neither_nor<{}, {}>",
+            P::expected_code(),
+            Q::expected_code()
+        )
+    }
+}
+
+/// Represents errors returned by [`NotBoth`].
+pub struct NotBothError<T: ?Sized, P: ?Sized, Q: ?Sized> {
+    value: PhantomData<T>,
+    left: PhantomData<P>,
+    right: PhantomData<Q>,
+}
+
+impl<T: ?Sized, P: ?Sized, Q: ?Sized> NotBothError<T, P, Q> {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self {
+            value: PhantomData,
+            left: PhantomData,
+            right: PhantomData,
+        }
+    }
+}
+
+impl<T: ?Sized, P: ?Sized, Q: ?Sized> Default for NotBothError<T, P, Q> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized, P: ?Sized, Q: ?Sized> fmt::Debug for NotBothError<T, P, Q> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("NotBothError")
+    }
+}
+
+impl<T: ?Sized, P: Predicate<T> + ?Sized, Q: Predicate<T> + ?Sized> fmt::Display
+    for NotBothError<T, P, Q>
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "expected not both ({}) and ({}), but both are satisfied",
+            P::expected(),
+            Q::expected()
+        )
+    }
+}
+
+impl<T: ?Sized, P: Predicate<T> + ?Sized, Q: Predicate<T> + ?Sized> ErrorCore
+    for NotBothError<T, P, Q>
+{
+}
+
+#[cfg(feature = "diagnostics")]
+impl<T: ?Sized, P: Predicate<T> + ?Sized, Q: Predicate<T> + ?Sized> Diagnostic
+    for NotBothError<T, P, Q>
+{
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("logic::not_both"))
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new(format!(
+            "make sure at most one of ({}) and ({}) is satisfied",
+            P::expected(),
+            Q::expected()
+        )))
+    }
+}
+
+/// Represents predicates that are satisfied unless both `P` and `Q` are satisfied.
+///
+/// This is equivalent to [`Nand`], except that errors are more descriptive.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::u8, logic::NotBoth};
+///
+/// type NotMiddle = NotBoth<u8::Greater<13>, u8::Less<42>>;
+///
+/// assert!(NotMiddle::is_satisfied(&69));
+///
+/// let error = NotMiddle::check(&25).unwrap_err();
+///
+/// assert_eq!(
+///     error.to_string(),
+///     "expected not both (u8 > 13) and (u8 < 42), but both are satisfied"
+/// );
+/// ```
+pub struct NotBoth<P: ?Sized, Q: ?Sized> {
+    left: PhantomData<P>,
+    right: PhantomData<Q>,
+}
+
+impl<T: ?Sized, P: Predicate<T> + ?Sized, Q: Predicate<T> + ?Sized> Predicate<T> for NotBoth<P, Q> {
+    type Error = NotBothError<T, P, Q>;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if P::is_satisfied(value) && Q::is_satisfied(value) {
+            Err(Self::Error::new())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "not both ({}) and ({})",
+            P::expected(),
+            Q::expected()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "not_both<{}, {}>",
+            P::expected_code(),
+            Q::expected_code()
+        )
+    }
+}
+
+/// Represents mappings of errors of type `E`, used in [`ErrMap`].
+pub trait MapError<E> {
+    /// The mapped error type.
+    type Output;
+
+    /// Maps the given error.
+    fn map(error: E) -> Self::Output;
+}
+
+/// Represents identity mappings, that is, mappings that return errors as-is.
+pub struct Identity {
+    private: PhantomData<()>,
+}
+
+impl<E> MapError<E> for Identity {
+    type Output = E;
+
+    fn map(error: E) -> Self::Output {
+        error
+    }
+}
+
+/// Represents mappings that convert errors into `U` via [`From`].
+pub struct Convert<U: ?Sized> {
+    output: PhantomData<U>,
+}
+
+impl<E, U: From<E>> MapError<E> for Convert<U> {
+    type Output = U;
+
+    fn map(error: E) -> Self::Output {
+        U::from(error)
+    }
+}
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+/// Represents boxed errors.
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub type BoxedError = Box<dyn ErrorCore + Send + Sync + 'static>;
+
+/// Represents mappings that box errors into [`BoxedError`].
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub struct Boxed {
+    private: PhantomData<()>,
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<E: ErrorCore + Send + Sync + 'static> MapError<E> for Boxed {
+    type Output = BoxedError;
+
+    fn map(error: E) -> Self::Output {
+        Box::new(error)
+    }
+}
+
+/// Represents predicates that are satisfied when `P` is satisfied,
+/// mapping the errors of `P` using `F`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     int::u8,
+///     logic::{Convert, ErrMap},
+///     str::{StartsWith, core::StartsWithError},
+///     type_str,
+/// };
+///
+/// #[derive(Debug)]
+/// enum AppError {
+///     Small(u8::GreaterError),
+///     Prefix(StartsWithError),
+/// }
+///
+/// impl From<u8::GreaterError> for AppError {
+///     fn from(error: u8::GreaterError) -> Self {
+///         Self::Small(error)
+///     }
+/// }
+///
+/// impl From<StartsWithError> for AppError {
+///     fn from(error: StartsWithError) -> Self {
+///         Self::Prefix(error)
+///     }
+/// }
+///
+/// type_str!(Nekit = "nekit");
+///
+/// type Large = ErrMap<u8::Greater<100>, Convert<AppError>>;
+/// type Prefixed = ErrMap<StartsWith<Nekit>, Convert<AppError>>;
+///
+/// let error = Large::check(&42).unwrap_err();
+///
+/// assert!(matches!(error, AppError::Small(u8::GreaterError { other: 100 })));
+///
+/// let error = Prefixed::check("dev").unwrap_err();
+///
+/// assert!(matches!(error, AppError::Prefix(StartsWithError { prefix: "nekit" })));
+///
+/// assert_eq!(Large::expected().to_string(), "u8 > 100");
+/// ```
+pub struct ErrMap<P: ?Sized, F: ?Sized> {
+    predicate: PhantomData<P>,
+    map: PhantomData<F>,
+}
+
+impl<T: ?Sized, P: Predicate<T> + ?Sized, F: MapError<P::Error> + ?Sized> Predicate<T>
+    for ErrMap<P, F>
+{
+    type Error = F::Output;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        P::check(value).map_err(F::map)
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        P::expect(formatter)
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        P::expect_code(formatter)
+    }
+}
+
+// tuple predicates
+
+/// Represents errors that occur when the length prefix does not match the body length.
+#[derive(Debug, Error)]
+#[error("received length prefix {prefix}, but body has length {length}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(logic::prefixed_length),
+        help("make sure the length prefix matches the body length")
+    )
+)]
+pub struct PrefixedLengthMatchesError {
+    /// The length prefix.
+    pub prefix: usize,
+    /// The body length.
+    pub length: usize,
+}
+
+impl PrefixedLengthMatchesError {
+    /// Constructs [`Self`].
+    pub const fn new(prefix: usize, length: usize) -> Self {
+        Self { prefix, length }
+    }
+}
+
+/// Checks whether the length prefix of `(prefix, body)` tuples matches the body length in bytes.
+///
+/// This is useful for validating length-prefixed (framed) messages.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, logic::PrefixedLengthMatches};
+///
+/// assert!(PrefixedLengthMatches::is_satisfied(&(5, "nekit")));
+///
+/// let error = PrefixedLengthMatches::check(&(4, "nekit")).unwrap_err();
+///
+/// assert_eq!((error.prefix, error.length), (4, 5));
+/// ```
+pub struct PrefixedLengthMatches {
+    private: PhantomData<()>,
+}
+
+impl<S: AsRef<str>> Predicate<(usize, S)> for PrefixedLengthMatches {
+    type Error = PrefixedLengthMatchesError;
+
+    fn check(value: &(usize, S)) -> Result<(), Self::Error> {
+        let (prefix, body) = value;
+
+        let length = body.as_ref().len();
+
+        if *prefix == length {
+            Ok(())
+        } else {
+            Err(Self::Error::new(*prefix, length))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("body with length matching the prefix")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("logic::prefixed_length")
+    }
+}
+
+/// Represents errors that occur when the port range is invalid.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+pub enum ValidPortRangeError {
+    /// Some port is not valid (that is, zero).
+    #[error("received invalid port {port}, expected 1-65535")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(logic::port_range::port),
+            help("make sure both ports are non-zero")
+        )
+    )]
+    Port {
+        /// The invalid port.
+        port: u16,
+    },
+    /// The range is inverted.
+    #[error("received inverted port range {start}-{end}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(logic::port_range::inverted),
+            help("make sure the start port is less than or equal to the end port")
+        )
+    )]
+    Inverted {
+        /// The start port.
+        start: u16,
+        /// The end port.
+        end: u16,
+    },
+}
+
+impl ValidPortRangeError {
+    /// Constructs [`Self::Port`].
+    pub const fn port(port: u16) -> Self {
+        Self::Port { port }
+    }
+
+    /// Constructs [`Self::Inverted`].
+    pub const fn inverted(start: u16, end: u16) -> Self {
+        Self::Inverted { start, end }
+    }
+}
+
+/// Checks whether `(start, end)` tuples are valid port ranges, that is, whether both ports
+/// are valid (`1-65535`) and `start <= end`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     logic::{ValidPortRange, ValidPortRangeError},
+/// };
+///
+/// assert!(ValidPortRange::is_satisfied(&(80, 443)));
+/// assert!(ValidPortRange::is_satisfied(&(8080, 8080)));
+///
+/// let error = ValidPortRange::check(&(443, 80)).unwrap_err();
+///
+/// assert!(matches!(error, ValidPortRangeError::Inverted { start: 443, end: 80 }));
+///
+/// let error = ValidPortRange::check(&(0, 100)).unwrap_err();
+///
+/// assert!(matches!(error, ValidPortRangeError::Port { port: 0 }));
+/// ```
+pub struct ValidPortRange {
+    private: PhantomData<()>,
+}
+
+impl Predicate<(u16, u16)> for ValidPortRange {
+    type Error = ValidPortRangeError;
+
+    fn check(value: &(u16, u16)) -> Result<(), Self::Error> {
+        let &(start, end) = value;
+
+        if let Some(port) = [start, end].into_iter().find(|&port| port == 0) {
+            return Err(Self::Error::port(port));
+        }
+
+        if start <= end {
+            Ok(())
+        } else {
+            Err(Self::Error::inverted(start, end))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("valid port range")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("logic::port_range")
+    }
+}
+
+macro_rules! markers {
+    ($($name: ident $(<$($generic: ident),+>)? { $($field: ident),+ }),+ $(,)?) => {
+        $(
+            impl$(<$($generic: ?Sized),+>)? Clone for $name$(<$($generic),+>)? {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl$(<$($generic: ?Sized),+>)? Copy for $name$(<$($generic),+>)? {}
+
+            impl$(<$($generic: ?Sized),+>)? Default for $name$(<$($generic),+>)? {
+                fn default() -> Self {
+                    Self {
+                        $($field: PhantomData),+
+                    }
+                }
+            }
+
+            impl$(<$($generic: ?Sized),+>)? fmt::Debug for $name$(<$($generic),+>)? {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str(stringify!($name))
+                }
+            }
+        )+
+    };
+}
+
+markers!(
+    True { private },
+    False { private },
+    And<P, Q> { left, right },
+    Or<P, Q> { left, right },
+    Not<P> { predicate },
+    Xor<P, Q> { left, right },
+    ExactlyOne<L> { predicates },
+    NeitherNor<P, Q> { left, right },
+    NotBoth<P, Q> { left, right },
+    ErrMap<P, F> { predicate, map },
+);
+
 /// Negates the given predicate.
 ///
 /// For predicate `P`, `not!(P)` is [`Not<P>`].
@@ -422,3 +1432,30 @@ macro_rules! xor {
         $crate::xor!($first, $crate::xor!($second, $($rest),+))
     }
 }
+
+/// Given one or more predicates, composes them together with [`ExactlyOne`].
+///
+/// For predicates `P`, `Q`, and `R`, `exactly_one!(P, Q, R)` is [`ExactlyOne<(P, Q, R)>`].
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, exactly_one, int::u8};
+///
+/// type Check = exactly_one!(u8::Less<10>, u8::Less<20>, u8::Greater<100>);
+///
+/// assert_eq!(Check::check(&50).unwrap_err().satisfied, 0);
+/// assert!(Check::is_satisfied(&15));
+/// assert_eq!(Check::check(&5).unwrap_err().satisfied, 2);
+///
+/// assert_eq!(
+///     Check::expected().to_string(),
+///     "exactly one of (u8 < 10), (u8 < 20), (u8 > 100)",
+/// );
+/// ```
+#[macro_export]
+macro_rules! exactly_one {
+    ($($predicate: ty),+ $(,)?) => {
+        $crate::logic::ExactlyOne<($($predicate,)+)>
+    };
+}
@@ -0,0 +1,267 @@
+//! Predicates based on maps.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use crate::core::{ErrorCore, Predicate};
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+
+#[cfg(all(not(feature = "alloc"), feature = "std"))]
+use std::collections::BTreeMap;
+
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+/// Represents errors that occur when some key does not satisfy the predicate.
+#[derive(Debug)]
+pub struct AllKeysError<K, E> {
+    /// The failing key.
+    pub key: K,
+    /// The error produced by the predicate.
+    pub error: E,
+}
+
+impl<K, E> AllKeysError<K, E> {
+    /// Constructs [`Self`].
+    pub const fn new(key: K, error: E) -> Self {
+        Self { key, error }
+    }
+}
+
+impl<K: fmt::Debug, E: fmt::Display> fmt::Display for AllKeysError<K, E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "error on key {key:?}: {error}",
+            key = self.key,
+            error = self.error
+        )
+    }
+}
+
+impl<K: fmt::Debug, E: ErrorCore + 'static> ErrorCore for AllKeysError<K, E> {
+    fn source(&self) -> Option<&(dyn ErrorCore + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+impl<K: fmt::Debug, E: Diagnostic + 'static> Diagnostic for AllKeysError<K, E> {
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("map::all_keys"))
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("make sure each key satisfies the predicate"))
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        Some(&self.error)
+    }
+}
+
+/// Checks if each key of the map satisfies the predicate `P`.
+///
+/// The first failing key is reported, which is the least one for ordered maps.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use refinement_types::{Predicate, map::AllKeys, str::EnvVarName};
+///
+/// let mut map = BTreeMap::new();
+///
+/// map.insert("PATH", "/bin");
+/// map.insert("HOME", "/home/nekit");
+///
+/// assert!(AllKeys::<EnvVarName>::is_satisfied(&map));
+///
+/// map.insert("1NVALID", "");
+///
+/// let error = AllKeys::<EnvVarName>::check(&map).unwrap_err();
+///
+/// assert_eq!(error.key, "1NVALID");
+/// ```
+pub struct AllKeys<P: ?Sized> {
+    predicate: PhantomData<P>,
+}
+
+impl<P: ?Sized> AllKeys<P> {
+    fn check_keys<'k, K: Clone + 'k, I: IntoIterator<Item = &'k K>>(
+        keys: I,
+    ) -> Result<(), AllKeysError<K, P::Error>>
+    where
+        P: Predicate<K>,
+    {
+        keys.into_iter().try_for_each(|key| {
+            P::check(key).map_err(|error| AllKeysError::new(key.clone(), error))
+        })
+    }
+}
+
+impl<K: Clone, V, P: Predicate<K> + ?Sized> Predicate<BTreeMap<K, V>> for AllKeys<P> {
+    type Error = AllKeysError<K, P::Error>;
+
+    fn check(value: &BTreeMap<K, V>) -> Result<(), Self::Error> {
+        Self::check_keys(value.keys())
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "map where each key is ({})", P::expected())
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "map::all_keys<{}>", P::expected_code())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Clone + Eq + Hash, V, S: BuildHasher, P: Predicate<K> + ?Sized> Predicate<HashMap<K, V, S>>
+    for AllKeys<P>
+{
+    type Error = AllKeysError<K, P::Error>;
+
+    fn check(value: &HashMap<K, V, S>) -> Result<(), Self::Error> {
+        Self::check_keys(value.keys())
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<BTreeMap<K, V>>>::expect(formatter)
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<BTreeMap<K, V>>>::expect_code(formatter)
+    }
+}
+
+/// Represents errors that occur when some value does not satisfy the predicate.
+#[derive(Debug)]
+pub struct AllValuesError<K, E> {
+    /// The key of the failing value.
+    pub key: K,
+    /// The error produced by the predicate.
+    pub error: E,
+}
+
+impl<K, E> AllValuesError<K, E> {
+    /// Constructs [`Self`].
+    pub const fn new(key: K, error: E) -> Self {
+        Self { key, error }
+    }
+}
+
+impl<K: fmt::Debug, E: fmt::Display> fmt::Display for AllValuesError<K, E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "error on value of key {key:?}: {error}",
+            key = self.key,
+            error = self.error
+        )
+    }
+}
+
+impl<K: fmt::Debug, E: ErrorCore + 'static> ErrorCore for AllValuesError<K, E> {
+    fn source(&self) -> Option<&(dyn ErrorCore + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+impl<K: fmt::Debug, E: Diagnostic + 'static> Diagnostic for AllValuesError<K, E> {
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("map::all_values"))
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("make sure each value satisfies the predicate"))
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        Some(&self.error)
+    }
+}
+
+/// Checks if each value of the map satisfies the predicate `P`.
+///
+/// The key of the first failing value is reported, which is the least one for ordered maps.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use refinement_types::{Predicate, empty::NonEmpty, map::AllValues};
+///
+/// let mut map = BTreeMap::new();
+///
+/// map.insert("PATH", "/bin");
+///
+/// assert!(AllValues::<NonEmpty>::is_satisfied(&map));
+///
+/// map.insert("HOME", "");
+///
+/// let error = AllValues::<NonEmpty>::check(&map).unwrap_err();
+///
+/// assert_eq!(error.key, "HOME");
+/// ```
+pub struct AllValues<P: ?Sized> {
+    predicate: PhantomData<P>,
+}
+
+impl<P: ?Sized> AllValues<P> {
+    fn check_values<'e, K: Clone + 'e, V: 'e, I: IntoIterator<Item = (&'e K, &'e V)>>(
+        entries: I,
+    ) -> Result<(), AllValuesError<K, P::Error>>
+    where
+        P: Predicate<V>,
+    {
+        entries.into_iter().try_for_each(|(key, value)| {
+            P::check(value).map_err(|error| AllValuesError::new(key.clone(), error))
+        })
+    }
+}
+
+impl<K: Clone, V, P: Predicate<V> + ?Sized> Predicate<BTreeMap<K, V>> for AllValues<P> {
+    type Error = AllValuesError<K, P::Error>;
+
+    fn check(value: &BTreeMap<K, V>) -> Result<(), Self::Error> {
+        Self::check_values(value)
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "map where each value is ({})", P::expected())
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "map::all_values<{}>", P::expected_code())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Clone + Eq + Hash, V, S: BuildHasher, P: Predicate<V> + ?Sized> Predicate<HashMap<K, V, S>>
+    for AllValues<P>
+{
+    type Error = AllValuesError<K, P::Error>;
+
+    fn check(value: &HashMap<K, V, S>) -> Result<(), Self::Error> {
+        Self::check_values(value)
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<BTreeMap<K, V>>>::expect(formatter)
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<BTreeMap<K, V>>>::expect_code(formatter)
+    }
+}
@@ -0,0 +1,111 @@
+//! Projections of values.
+//!
+//! Projections allow applying predicates to parts of values, for instance,
+//! to fields of structures or to dereference targets.
+
+use core::{fmt, marker::PhantomData, ops::Deref};
+
+use crate::core::Predicate;
+
+/// Represents projections of values of type `T`.
+pub trait Project<T: ?Sized> {
+    /// The projection target type.
+    type Target: ?Sized;
+
+    /// Projects the given value.
+    fn project(value: &T) -> &Self::Target;
+}
+
+/// Projects values to their dereference targets.
+pub struct Dereference {
+    private: PhantomData<()>,
+}
+
+impl<T: Deref + ?Sized> Project<T> for Dereference {
+    type Target = T::Target;
+
+    fn project(value: &T) -> &Self::Target {
+        value.deref()
+    }
+}
+
+/// Projects values to their string slices.
+pub struct AsStr {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Project<T> for AsStr {
+    type Target = str;
+
+    fn project(value: &T) -> &Self::Target {
+        value.as_ref()
+    }
+}
+
+/// Projects values to their byte slices.
+pub struct AsBytes {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Project<T> for AsBytes {
+    type Target = [u8];
+
+    fn project(value: &T) -> &Self::Target {
+        value.as_ref()
+    }
+}
+
+/// Checks whether the projection of the value via `G` satisfies the predicate `P`.
+///
+/// The errors and expectations are the same as those of the predicate `P`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     project::{Project, Via},
+///     str::Ascii,
+/// };
+///
+/// struct Name {
+///     value: String,
+/// }
+///
+/// struct Value;
+///
+/// impl Project<Name> for Value {
+///     type Target = str;
+///
+///     fn project(name: &Name) -> &Self::Target {
+///         &name.value
+///     }
+/// }
+///
+/// type AsciiName = Via<Value, Ascii>;
+///
+/// assert!(AsciiName::is_satisfied(&Name { value: "nekit".to_owned() }));
+/// assert!(!AsciiName::is_satisfied(&Name { value: "некит".to_owned() }));
+/// ```
+pub struct Via<G: ?Sized, P: ?Sized> {
+    projection: PhantomData<G>,
+    predicate: PhantomData<P>,
+}
+
+impl<T: ?Sized, G: Project<T> + ?Sized, P: Predicate<G::Target> + ?Sized> Predicate<T>
+    for Via<G, P>
+{
+    type Error = P::Error;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        P::check(G::project(value))
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        P::expect(formatter)
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        P::expect_code(formatter)
+    }
+}
@@ -0,0 +1,287 @@
+//! Predicates based on slices.
+//!
+//! Note that predicates in [`length`] apply to slices as well;
+//! the ones commonly used with slices are re-exported here.
+//!
+//! [`length`]: crate::length
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::{
+    core::{Predicate, Refinement},
+    type_str::TypeStr,
+};
+
+pub use crate::length::PowerOfTwoLength;
+
+/// Represents types that have distance defined for their values.
+pub trait Distance: Copy {
+    /// Returns the absolute difference between the values.
+    fn distance(self, other: Self) -> u128;
+}
+
+macro_rules! distance {
+    ($($int: ty),+ $(,)?) => {
+        $(
+            impl Distance for $int {
+                #[allow(clippy::cast_lossless)]
+                fn distance(self, other: Self) -> u128 {
+                    self.abs_diff(other) as u128
+                }
+            }
+        )+
+    };
+}
+
+distance!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize,
+);
+
+/// Represents errors that occur when adjacent elements differ by more than some delta.
+#[derive(Debug, Error)]
+#[error("received elements at {index} and {next} differing by {delta} > {max}", next = index + 1)]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(slice::max_adjacent_delta),
+        help("make sure adjacent elements differ by at most {max}")
+    )
+)]
+pub struct MaxAdjacentDeltaError {
+    /// The index of the first element of the failing pair.
+    pub index: usize,
+    /// The difference between the elements of the failing pair.
+    pub delta: u128,
+    /// The maximum allowed difference (the `D`).
+    pub max: u128,
+}
+
+impl MaxAdjacentDeltaError {
+    /// Constructs [`Self`].
+    pub const fn new(index: usize, delta: u128, max: u128) -> Self {
+        Self { index, delta, max }
+    }
+}
+
+/// Checks whether adjacent elements of the slice differ by at most `D`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, slice::MaxAdjacentDelta};
+///
+/// assert!(MaxAdjacentDelta::<2>::is_satisfied(&[10, 11, 13, 12][..]));
+///
+/// let error = MaxAdjacentDelta::<2>::check(&[10, 11, 20, 19][..]).unwrap_err();
+///
+/// assert_eq!(error.index, 1);
+/// assert_eq!(error.delta, 9);
+/// ```
+pub struct MaxAdjacentDelta<const D: u128> {
+    private: PhantomData<()>,
+}
+
+impl<const D: u128> MaxAdjacentDelta<D> {
+    fn check_elements<T: Distance>(elements: &[T]) -> Result<(), MaxAdjacentDeltaError> {
+        elements
+            .windows(2)
+            .enumerate()
+            .map(|(index, window)| (index, window[0].distance(window[1])))
+            .find(|&(_, delta)| delta > D)
+            .map_or(Ok(()), |(index, delta)| {
+                Err(MaxAdjacentDeltaError::new(index, delta, D))
+            })
+    }
+}
+
+impl<T: Distance, const D: u128> Predicate<[T]> for MaxAdjacentDelta<D> {
+    type Error = MaxAdjacentDeltaError;
+
+    fn check(value: &[T]) -> Result<(), Self::Error> {
+        Self::check_elements(value)
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "slice with adjacent elements differing by <= {D}"
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "slice::max_adjacent_delta<{D}>")
+    }
+}
+
+impl<T: Distance, const D: u128, const N: usize> Predicate<[T; N]> for MaxAdjacentDelta<D> {
+    type Error = MaxAdjacentDeltaError;
+
+    fn check(value: &[T; N]) -> Result<(), Self::Error> {
+        Self::check_elements(value)
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<[T]>>::expect(formatter)
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<[T]>>::expect_code(formatter)
+    }
+}
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T: Distance, const D: u128> Predicate<Vec<T>> for MaxAdjacentDelta<D> {
+    type Error = MaxAdjacentDeltaError;
+
+    fn check(value: &Vec<T>) -> Result<(), Self::Error> {
+        Self::check_elements(value)
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<[T]>>::expect(formatter)
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<[T]>>::expect_code(formatter)
+    }
+}
+
+/// Represents errors that occur when some element of the slice is [`None`].
+#[derive(Debug, Error)]
+#[error("received `None` at index {index}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(code(slice::none_free), help("make sure all elements are `Some`"))
+)]
+pub struct NoneFreeError {
+    /// The index of the first [`None`] element.
+    pub index: usize,
+}
+
+impl NoneFreeError {
+    /// Constructs [`Self`].
+    pub const fn new(index: usize) -> Self {
+        Self { index }
+    }
+}
+
+/// Checks whether all elements of the slice are [`Some`].
+///
+/// Refined arrays and vectors can be unwrapped infallibly via [`unwrap_all`].
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, Refinement, slice::NoneFree};
+///
+/// assert!(NoneFree::is_satisfied(&[Some(1), Some(2), Some(3)][..]));
+///
+/// let error = NoneFree::check(&[Some(1), None, Some(3)][..]).unwrap_err();
+///
+/// assert_eq!(error.index, 1);
+///
+/// let values = Refinement::<_, NoneFree>::refine(vec![Some(1), Some(2)]).unwrap();
+///
+/// assert_eq!(values.unwrap_all(), vec![1, 2]);
+/// ```
+///
+/// [`unwrap_all`]: Refinement::unwrap_all
+pub struct NoneFree {
+    private: PhantomData<()>,
+}
+
+impl NoneFree {
+    fn check_elements<T>(elements: &[Option<T>]) -> Result<(), NoneFreeError> {
+        elements
+            .iter()
+            .position(Option::is_none)
+            .map_or(Ok(()), |index| Err(NoneFreeError::new(index)))
+    }
+}
+
+impl<T> Predicate<[Option<T>]> for NoneFree {
+    type Error = NoneFreeError;
+
+    fn check(value: &[Option<T>]) -> Result<(), Self::Error> {
+        Self::check_elements(value)
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("slice without `None` elements")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("slice::none_free")
+    }
+}
+
+impl<T, const N: usize> Predicate<[Option<T>; N]> for NoneFree {
+    type Error = NoneFreeError;
+
+    fn check(value: &[Option<T>; N]) -> Result<(), Self::Error> {
+        Self::check_elements(value)
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<[Option<T>]>>::expect(formatter)
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<[Option<T>]>>::expect_code(formatter)
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T> Predicate<Vec<Option<T>>> for NoneFree {
+    type Error = NoneFreeError;
+
+    fn check(value: &Vec<Option<T>>) -> Result<(), Self::Error> {
+        Self::check_elements(value)
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<[Option<T>]>>::expect(formatter)
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <Self as Predicate<[Option<T>]>>::expect_code(formatter)
+    }
+}
+
+impl<T, const N: usize, C: TypeStr + ?Sized> Refinement<[Option<T>; N], NoneFree, C> {
+    /// Unwraps all elements of the array.
+    ///
+    /// This is infallible, as all elements are known to be [`Some`].
+    pub fn unwrap_all(self) -> [T; N] {
+        self.take().map(|element| {
+            // SAFETY: all elements are `Some`
+            unsafe { element.unwrap_unchecked() }
+        })
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T, C: TypeStr + ?Sized> Refinement<Vec<Option<T>>, NoneFree, C> {
+    /// Unwraps all elements of the vector.
+    ///
+    /// This is infallible, as all elements are known to be [`Some`].
+    pub fn unwrap_all(self) -> Vec<T> {
+        self.take()
+            .into_iter()
+            .map(|element| {
+                // SAFETY: all elements are `Some`
+                unsafe { element.unwrap_unchecked() }
+            })
+            .collect()
+    }
+}
@@ -2,3 +2,27 @@
 
 /// Represents static strings.
 pub type StaticStr = &'static str;
+
+/// Checks whether the given static strings are equal.
+///
+/// Unlike [`PartialEq`], this function can be used in `const` contexts.
+pub const fn equal(left: StaticStr, right: StaticStr) -> bool {
+    let left = left.as_bytes();
+    let right = right.as_bytes();
+
+    if left.len() != right.len() {
+        return false;
+    }
+
+    let mut index = 0;
+
+    while index < left.len() {
+        if left[index] != right[index] {
+            return false;
+        }
+
+        index += 1;
+    }
+
+    true
+}
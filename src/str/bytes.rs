@@ -1 +1,356 @@
 //! Predicates based on bytes of strings.
+
+use core::{any, fmt, marker::PhantomData, mem};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::{
+    core::{Predicate, Refinement},
+    static_str::StaticStr,
+    str::Ascii,
+    type_bytes::{StaticBytes, TypeBytes},
+    type_str::TypeStr,
+};
+
+/// Represents string predicates that imply byte predicates.
+///
+/// # Safety
+///
+/// Implementors must ensure that for every string satisfying the predicate,
+/// its bytes satisfy the predicate `Q`.
+pub unsafe trait ImpliesBytes<Q: ?Sized> {}
+
+impl<T: AsRef<str>, P: Predicate<T> + ?Sized, C: TypeStr + ?Sized> Refinement<T, P, C> {
+    /// Returns the bytes of the refined string.
+    pub fn as_bytes(&self) -> &[u8] {
+        self.get().as_ref().as_bytes()
+    }
+
+    /// Returns the bytes of the refined string, refined with the implied predicate `Q`.
+    ///
+    /// No checks are needed, as `P` implies `Q` (see [`ImpliesBytes`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{
+    ///     Refinement,
+    ///     str::{Ascii, bytes::AsciiBytes},
+    /// };
+    ///
+    /// let string = Refinement::<String, Ascii>::refine("nekit".to_owned()).unwrap();
+    ///
+    /// let bytes = string.as_bytes_refined::<AsciiBytes>();
+    ///
+    /// assert_eq!(*bytes, b"nekit");
+    /// ```
+    pub fn as_bytes_refined<Q: for<'b> Predicate<&'b [u8]> + ?Sized>(
+        &self,
+    ) -> Refinement<&[u8], Q, C>
+    where
+        P: ImpliesBytes<Q>,
+    {
+        // SAFETY: `P` implies `Q`, and the string satisfies `P`
+        unsafe { Refinement::unchecked(self.as_bytes()) }
+    }
+}
+
+/// Represents errors that occur when the bytes are not valid ASCII.
+#[derive(Debug, Error, Default)]
+#[error("expected bytes to be ascii")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(code(str::bytes::ascii), help("make sure the bytes are ascii"))
+)]
+pub struct AsciiBytesError;
+
+impl AsciiBytesError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the bytes are valid ASCII.
+pub struct AsciiBytes {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Predicate<T> for AsciiBytes {
+    type Error = AsciiBytesError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if value.as_ref().is_ascii() {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("ascii bytes")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::bytes::ascii")
+    }
+}
+
+// SAFETY: ascii strings consist of ascii bytes
+unsafe impl ImpliesBytes<AsciiBytes> for Ascii {}
+
+/// Represents errors that occur when the bytes are not sorted.
+#[derive(Debug, Error)]
+#[error("expected bytes to be sorted, found inversion at index {index}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::bytes::sorted),
+        help("make sure the bytes are in non-decreasing order")
+    )
+)]
+pub struct SortedError {
+    /// The index of the first byte that is less than the preceding one.
+    pub index: usize,
+}
+
+impl SortedError {
+    /// Constructs [`Self`].
+    pub const fn new(index: usize) -> Self {
+        Self { index }
+    }
+}
+
+/// Checks if the bytes are sorted, that is, are in non-decreasing order.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::bytes::Sorted};
+///
+/// assert!(Sorted::is_satisfied(b"abbc"));
+/// assert!(Sorted::is_satisfied(b""));
+///
+/// let error = Sorted::check(b"abca").unwrap_err();
+///
+/// assert_eq!(error.index, 3);
+/// ```
+pub struct Sorted {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<[u8]> + ?Sized> Predicate<T> for Sorted {
+    type Error = SortedError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        value
+            .as_ref()
+            .windows(2)
+            .position(|window| window[0] > window[1])
+            .map_or(Ok(()), |position| Err(Self::Error::new(position + 1)))
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("sorted bytes")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::bytes::sorted")
+    }
+}
+
+/// Represents errors that occur when the bytes do not start with the expected magic.
+#[derive(Debug, Error)]
+#[error(
+    "expected bytes to start with magic {expected:02X?}, found {found:02X?} at offset {offset}"
+)]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::bytes::magic),
+        help("make sure the bytes start with {expected:02X?}")
+    )
+)]
+pub struct MagicError {
+    /// The expected magic.
+    pub expected: StaticBytes,
+    /// The offset of the first mismatching byte.
+    pub offset: usize,
+    /// The mismatching byte, or [`None`] if the bytes are too short.
+    pub found: Option<u8>,
+}
+
+impl MagicError {
+    /// Constructs [`Self`].
+    pub const fn new(expected: StaticBytes, offset: usize, found: Option<u8>) -> Self {
+        Self {
+            expected,
+            offset,
+            found,
+        }
+    }
+}
+
+/// Checks if the bytes start with the magic `S`, which is commonly used to identify file formats.
+///
+/// In order to check the exact length as well, combine this predicate with [`length::Equal`].
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::bytes::Magic, type_bytes};
+///
+/// type_bytes!(Zip = b"PK\x03\x04");
+///
+/// assert!(Magic::<Zip>::is_satisfied(b"PK\x03\x04\x14\x00"));
+///
+/// let error = Magic::<Zip>::check(b"PK\x05\x06").unwrap_err();
+///
+/// assert_eq!((error.offset, error.found), (2, Some(0x05)));
+///
+/// let error = Magic::<Zip>::check(b"PK").unwrap_err();
+///
+/// assert_eq!((error.offset, error.found), (2, None));
+/// ```
+///
+/// [`length::Equal`]: crate::length::Equal
+pub struct Magic<S: TypeBytes + ?Sized> {
+    magic: PhantomData<S>,
+}
+
+impl<S: TypeBytes + ?Sized> Magic<S> {
+    /// Returns the expected magic.
+    pub const fn magic() -> StaticBytes {
+        S::VALUE
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized, S: TypeBytes + ?Sized> Predicate<T> for Magic<S> {
+    type Error = MagicError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let magic = Self::magic();
+        let bytes = value.as_ref();
+
+        magic
+            .iter()
+            .enumerate()
+            .find(|&(offset, expected)| bytes.get(offset) != Some(expected))
+            .map_or(Ok(()), |(offset, _)| {
+                Err(Self::Error::new(magic, offset, bytes.get(offset).copied()))
+            })
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "bytes starting with magic {magic:02X?}",
+            magic = Self::magic()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::bytes::magic")
+    }
+}
+
+/// Represents errors that occur when the length of bytes does not match the size of the type.
+#[derive(Debug, Error)]
+#[error("received {length} bytes, expected {size} bytes (the size of `{target}`)")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::bytes::exactly_size_of),
+        help("make sure there are exactly {size} bytes")
+    )
+)]
+pub struct ExactlySizeOfError {
+    /// The received number of bytes.
+    pub length: usize,
+    /// The size of the type.
+    pub size: usize,
+    /// The name of the type.
+    pub target: StaticStr,
+}
+
+impl ExactlySizeOfError {
+    /// Constructs [`Self`].
+    pub const fn new(length: usize, size: usize, target: StaticStr) -> Self {
+        Self {
+            length,
+            size,
+            target,
+        }
+    }
+}
+
+/// Checks if the length of bytes is exactly the size of `U`, as per [`mem::size_of`].
+///
+/// This is useful for validating buffers before reading values from them,
+/// for instance, via [`u32::from_ne_bytes`].
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::bytes::ExactlySizeOf};
+///
+/// assert!(ExactlySizeOf::<u32>::is_satisfied(&[1, 2, 3, 4]));
+///
+/// let error = ExactlySizeOf::<u32>::check(&[1, 2, 3]).unwrap_err();
+///
+/// assert_eq!((error.length, error.size), (3, 4));
+/// ```
+pub struct ExactlySizeOf<U> {
+    target: PhantomData<U>,
+}
+
+impl<U> ExactlySizeOf<U> {
+    /// Returns the size of `U`.
+    pub const fn size() -> usize {
+        mem::size_of::<U>()
+    }
+
+    /// Returns the name of `U`.
+    pub fn target() -> StaticStr {
+        any::type_name::<U>()
+    }
+}
+
+impl<T: AsRef<[u8]> + ?Sized, U> Predicate<T> for ExactlySizeOf<U> {
+    type Error = ExactlySizeOfError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let length = value.as_ref().len();
+        let size = Self::size();
+
+        if length == size {
+            Ok(())
+        } else {
+            Err(Self::Error::new(length, size, Self::target()))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "{size} bytes (the size of `{target}`)",
+            size = Self::size(),
+            target = Self::target()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "str::bytes::exactly_size_of<{target}>",
+            target = Self::target()
+        )
+    }
+}
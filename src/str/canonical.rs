@@ -0,0 +1,145 @@
+//! Predicates based on canonical forms of strings.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::{core::Predicate, static_str::StaticStr};
+
+/// Represents canonical forms of strings.
+pub trait Canonicalize {
+    /// The name of the canonical form.
+    const NAME: StaticStr;
+
+    /// Returns the characters of the canonical form of the given string.
+    fn canonicalize(string: &str) -> impl Iterator<Item = char>;
+}
+
+/// Represents canonical forms without leading and trailing whitespace.
+pub struct Trim {
+    private: PhantomData<()>,
+}
+
+impl Canonicalize for Trim {
+    const NAME: StaticStr = "trimmed";
+
+    fn canonicalize(string: &str) -> impl Iterator<Item = char> {
+        string.trim().chars()
+    }
+}
+
+/// Represents canonical forms with ASCII letters in lowercase.
+pub struct AsciiLowercase {
+    private: PhantomData<()>,
+}
+
+impl Canonicalize for AsciiLowercase {
+    const NAME: StaticStr = "ascii lowercase";
+
+    fn canonicalize(string: &str) -> impl Iterator<Item = char> {
+        string
+            .chars()
+            .map(|character| character.to_ascii_lowercase())
+    }
+}
+
+/// Represents canonical forms of hexadecimal strings, with digits in lowercase.
+///
+/// Characters other than hexadecimal digits are removed, so only the strings
+/// consisting of lowercase hexadecimal digits are in this canonical form.
+pub struct LowercaseHex {
+    private: PhantomData<()>,
+}
+
+impl Canonicalize for LowercaseHex {
+    const NAME: StaticStr = "lowercase hex";
+
+    fn canonicalize(string: &str) -> impl Iterator<Item = char> {
+        string
+            .chars()
+            .filter(char::is_ascii_hexdigit)
+            .map(|character| character.to_ascii_lowercase())
+    }
+}
+
+/// Represents errors that occur when the string is not in the expected canonical [`form`].
+///
+/// [`form`]: Self::form
+#[derive(Debug, Error)]
+#[error("expected string to be in {form} canonical form")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::canonical),
+        help("make sure the string is in {form} canonical form")
+    )
+)]
+pub struct CanonicalError {
+    /// The name of the canonical form.
+    pub form: StaticStr,
+}
+
+impl CanonicalError {
+    /// Constructs [`Self`].
+    pub const fn new(form: StaticStr) -> Self {
+        Self { form }
+    }
+}
+
+/// Checks if the string is equal to its canonical form, as defined by `F`.
+///
+/// The comparison is done character by character, without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     str::{
+///         Canonical,
+///         canonical::{AsciiLowercase, LowercaseHex, Trim},
+///     },
+/// };
+///
+/// assert!(Canonical::<LowercaseHex>::is_satisfied("deadbeef"));
+/// assert!(!Canonical::<LowercaseHex>::is_satisfied("DEADBEEF"));
+/// assert!(!Canonical::<LowercaseHex>::is_satisfied("xyz!"));
+///
+/// assert!(Canonical::<AsciiLowercase>::is_satisfied("xyz!"));
+/// assert!(!Canonical::<AsciiLowercase>::is_satisfied("XYZ!"));
+///
+/// assert!(Canonical::<Trim>::is_satisfied("nekit"));
+///
+/// let error = Canonical::<Trim>::check(" nekit\n").unwrap_err();
+///
+/// assert_eq!(error.form, "trimmed");
+/// ```
+pub struct Canonical<F: Canonicalize + ?Sized> {
+    form: PhantomData<F>,
+}
+
+impl<T: AsRef<str> + ?Sized, F: Canonicalize + ?Sized> Predicate<T> for Canonical<F> {
+    type Error = CanonicalError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let string = value.as_ref();
+
+        if string.chars().eq(F::canonicalize(string)) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(F::NAME))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "string in {form} canonical form", form = F::NAME)
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::canonical")
+    }
+}
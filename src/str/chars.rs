@@ -1 +1,755 @@
 //! Predicates based on characters of strings.
+
+use core::{fmt, iter::FusedIterator, marker::PhantomData, str::Chars};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::{
+    core::{ErrorCore, Predicate, Refinement},
+    int::usize::LessOrEqual,
+    type_str::TypeStr,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeSet;
+
+#[cfg(all(not(feature = "alloc"), feature = "std"))]
+use std::collections::BTreeSet;
+
+/// Finds the first character that occurs earlier in the string.
+///
+/// ASCII characters are tracked using the bitset, while other characters are tracked
+/// using [`BTreeSet`] if allocation is available, falling back to scanning the string otherwise.
+#[cfg_attr(any(feature = "alloc", feature = "std"), allow(unused_variables))]
+fn first_repeated(string: &str) -> Option<char> {
+    let mut ascii = 0u128;
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    let mut seen = BTreeSet::new();
+
+    for (index, character) in string.char_indices() {
+        let repeated = if character.is_ascii() {
+            let bit = 1 << (character as u32);
+
+            let repeated = ascii & bit != 0;
+
+            ascii |= bit;
+
+            repeated
+        } else {
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            let repeated = !seen.insert(character);
+
+            #[cfg(not(any(feature = "alloc", feature = "std")))]
+            let repeated = string[..index].contains(character);
+
+            repeated
+        };
+
+        if repeated {
+            return Some(character);
+        }
+    }
+
+    None
+}
+
+/// Represents errors that occur when the string contains repeated characters.
+#[derive(Debug, Error)]
+#[error("expected string to not repeat {character:?}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::unique_chars),
+        help("make sure the string does not contain repeated characters")
+    )
+)]
+pub struct UniqueCharsError {
+    /// The first repeated character.
+    pub character: char,
+}
+
+impl UniqueCharsError {
+    /// Constructs [`Self`].
+    pub const fn new(character: char) -> Self {
+        Self { character }
+    }
+}
+
+/// Checks if the string contains no repeated characters.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::UniqueChars};
+///
+/// assert!(UniqueChars::is_satisfied("abc"));
+/// assert!(UniqueChars::is_satisfied(""));
+///
+/// let error = UniqueChars::check("aba").unwrap_err();
+///
+/// assert_eq!(error.character, 'a');
+/// ```
+pub struct UniqueChars {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for UniqueChars {
+    type Error = UniqueCharsError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        first_repeated(value.as_ref()).map_or(Ok(()), |character| Err(Self::Error::new(character)))
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("string with unique characters")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::unique_chars")
+    }
+}
+
+/// The number of fractional bits used in entropy computations.
+const FRACTION: u32 = 16;
+
+/// Computes `log2(value)` in fixed point, with [`FRACTION`] fractional bits.
+///
+/// The integer part is computed via [`u32::ilog2`], and the fractional part is computed
+/// bit by bit via repeated squaring of the normalized value.
+const fn log2_fixed(value: u32) -> u64 {
+    let integer = value.ilog2();
+
+    // normalize the value to `[1, 2)`, with `32` fractional bits
+    let mut normalized = ((value as u128) << 32) >> integer;
+
+    let mut result = (integer as u64) << FRACTION;
+
+    let mut bit = FRACTION;
+
+    while bit > 0 {
+        bit -= 1;
+
+        normalized = (normalized * normalized) >> 32;
+
+        if normalized >= 2 << 32 {
+            normalized >>= 1;
+
+            result |= 1 << bit;
+        }
+    }
+
+    result
+}
+
+/// The number of ASCII lowercase letters.
+const LOWERCASE: u32 = 26;
+
+/// The number of ASCII uppercase letters.
+const UPPERCASE: u32 = 26;
+
+/// The number of ASCII digits.
+const DIGITS: u32 = 10;
+
+/// The number of ASCII punctuation characters, including the space.
+const SYMBOLS: u32 = 33;
+
+/// The number of characters assumed for any other character class.
+const OTHER: u32 = 64;
+
+/// Estimates the entropy of the given string, in fixed point with [`FRACTION`] fractional bits.
+fn entropy(string: &str) -> u64 {
+    let (mut lowercase, mut uppercase, mut digits, mut symbols, mut other) =
+        (false, false, false, false, false);
+
+    let mut length = 0u64;
+
+    for character in string.chars() {
+        length += 1;
+
+        match character {
+            'a'..='z' => lowercase = true,
+            'A'..='Z' => uppercase = true,
+            '0'..='9' => digits = true,
+            ' ' => symbols = true,
+            _ if character.is_ascii_punctuation() => symbols = true,
+            _ => other = true,
+        }
+    }
+
+    let pool = [
+        (lowercase, LOWERCASE),
+        (uppercase, UPPERCASE),
+        (digits, DIGITS),
+        (symbols, SYMBOLS),
+        (other, OTHER),
+    ]
+    .into_iter()
+    .filter_map(|(present, size)| present.then_some(size))
+    .sum::<u32>();
+
+    if pool == 0 {
+        0
+    } else {
+        length.saturating_mul(log2_fixed(pool))
+    }
+}
+
+/// Represents errors that occur when the estimated entropy of the string
+/// is less than [`bits`].
+///
+/// [`bits`]: Self::bits
+#[derive(Debug, Error)]
+#[error("expected string to have at least {bits} bits of entropy")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::min_entropy),
+        help("make sure the string is longer or uses more character classes")
+    )
+)]
+pub struct MinEntropyBitsError {
+    /// The minimum number of bits (the `BITS`).
+    pub bits: u32,
+}
+
+impl MinEntropyBitsError {
+    /// Constructs [`Self`].
+    pub const fn new(bits: u32) -> Self {
+        Self { bits }
+    }
+}
+
+/// Checks if the estimated entropy of the string is at least `BITS` bits.
+///
+/// The estimation is based on character classes rather than on character frequencies.
+/// Each class present in the string adds its size to the pool:
+///
+/// - ASCII lowercase letters: `26`;
+/// - ASCII uppercase letters: `26`;
+/// - ASCII digits: `10`;
+/// - ASCII punctuation and the space: `33`;
+/// - any other characters: `64`.
+///
+/// The entropy is then estimated as `length * log2(pool)`, where `length` is the number
+/// of characters. This is an upper bound on the entropy of uniformly random strings
+/// drawn from the pool, thus it overestimates the strength of predictable strings.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::MinEntropyBits};
+///
+/// // 4 * log2(26) ~ 18.8 bits
+/// assert!(!MinEntropyBits::<30>::is_satisfied("aaaa"));
+///
+/// // 6 * log2(95) ~ 39.4 bits
+/// assert!(MinEntropyBits::<30>::is_satisfied("a9X!qZ"));
+/// ```
+pub struct MinEntropyBits<const BITS: u32> {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized, const BITS: u32> Predicate<T> for MinEntropyBits<BITS> {
+    type Error = MinEntropyBitsError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if entropy(value.as_ref()) >= u64::from(BITS) << FRACTION {
+            Ok(())
+        } else {
+            Err(Self::Error::new(BITS))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "string with at least {BITS} bits of entropy")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::min_entropy<{BITS}>")
+    }
+}
+
+/// Checks if the integer is a valid code point budget, that is, at most `MAX`.
+///
+/// This is meant to be used with the number of characters in strings,
+/// as opposed to their length in bytes.
+pub type CodePointBudget<const MAX: usize> = LessOrEqual<MAX>;
+
+/// Represents errors that occur when the string has too many code points.
+#[derive(Debug, Error)]
+#[error("received string with {count} code points, expected at most {max}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::total_code_points_at_most),
+        help("make sure the string has at most {max} characters")
+    )
+)]
+pub struct TotalCodePointsAtMostError {
+    /// The number of code points in the string.
+    pub count: usize,
+    /// The maximum number of code points (the `MAX`).
+    pub max: usize,
+}
+
+impl TotalCodePointsAtMostError {
+    /// Constructs [`Self`].
+    pub const fn new(count: usize, max: usize) -> Self {
+        Self { count, max }
+    }
+}
+
+/// Checks if the string has at most `MAX` code points (characters).
+///
+/// This is the common "at most `MAX` characters" constraint, which is distinct from
+/// [`LessOrEqual`] on strings, as the latter bounds the length in bytes.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::TotalCodePointsAtMost};
+///
+/// let emojis = "🦀🦀🦀🦀🦀";
+///
+/// assert_eq!(emojis.len(), 20);
+///
+/// assert!(TotalCodePointsAtMost::<5>::is_satisfied(emojis));
+///
+/// let error = TotalCodePointsAtMost::<4>::check(emojis).unwrap_err();
+///
+/// assert_eq!(error.count, 5);
+/// ```
+///
+/// [`LessOrEqual`]: crate::length::LessOrEqual
+pub struct TotalCodePointsAtMost<const MAX: usize> {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized, const MAX: usize> Predicate<T> for TotalCodePointsAtMost<MAX> {
+    type Error = TotalCodePointsAtMostError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let count = value.as_ref().chars().count();
+
+        CodePointBudget::<MAX>::check(&count).map_err(|_| Self::Error::new(count, MAX))
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "string with at most {MAX} code points")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::total_code_points_at_most<{MAX}>")
+    }
+}
+
+/// Represents errors that occur when the string is not a palindrome.
+#[derive(Debug, Error, Default)]
+#[error("expected string to be a palindrome")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::palindrome),
+        help("make sure the string reads the same backwards")
+    )
+)]
+pub struct PalindromeError;
+
+impl PalindromeError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the string is a palindrome, that is, whether its characters
+/// read the same backwards.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::Palindrome};
+///
+/// assert!(Palindrome::is_satisfied("racecar"));
+/// assert!(Palindrome::is_satisfied(""));
+///
+/// assert!(!Palindrome::is_satisfied("Racecar"));
+/// ```
+pub struct Palindrome {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for Palindrome {
+    type Error = PalindromeError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let string = value.as_ref();
+
+        if string.chars().eq(string.chars().rev()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("palindrome")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::palindrome")
+    }
+}
+
+/// Represents errors that occur when the string is not a palindrome after normalization.
+#[derive(Debug, Error, Default)]
+#[error("expected string to be a palindrome, ignoring case and non-alphanumeric characters")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::normalized_palindrome),
+        help("make sure the alphanumeric characters read the same backwards, ignoring case")
+    )
+)]
+pub struct NormalizedPalindromeError;
+
+impl NormalizedPalindromeError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the string is a palindrome after normalization, that is, whether its
+/// alphanumeric characters read the same backwards, ignoring case.
+///
+/// Normalization is done lazily from both ends, so no allocation is needed.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::NormalizedPalindrome};
+///
+/// assert!(NormalizedPalindrome::is_satisfied("A man, a plan, a canal: Panama"));
+///
+/// assert!(!NormalizedPalindrome::is_satisfied("A man, a plan, a canal: Suez"));
+/// ```
+pub struct NormalizedPalindrome {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for NormalizedPalindrome {
+    type Error = NormalizedPalindromeError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let characters = value
+            .as_ref()
+            .chars()
+            .filter(|character| character.is_alphanumeric());
+
+        let forward = characters.clone().flat_map(char::to_lowercase);
+
+        let backward = characters
+            .rev()
+            .flat_map(|character| character.to_lowercase().rev());
+
+        if forward.eq(backward) {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("normalized palindrome")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::normalized_palindrome")
+    }
+}
+
+/// Represents errors that occur when some character does not satisfy the predicate.
+#[derive(Debug)]
+pub struct ForAllCharsError<E> {
+    /// The byte index of the failing character.
+    pub index: usize,
+    /// The failing character.
+    pub character: char,
+    /// The error produced by the predicate.
+    pub error: E,
+}
+
+impl<E> ForAllCharsError<E> {
+    /// Constructs [`Self`].
+    pub const fn new(index: usize, character: char, error: E) -> Self {
+        Self {
+            index,
+            character,
+            error,
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ForAllCharsError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "error on character {character:?} at index {index}: {error}",
+            character = self.character,
+            index = self.index,
+            error = self.error
+        )
+    }
+}
+
+impl<E: ErrorCore + 'static> ErrorCore for ForAllCharsError<E> {
+    fn source(&self) -> Option<&(dyn ErrorCore + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+impl<E: Diagnostic + 'static> Diagnostic for ForAllCharsError<E> {
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("str::for_all_chars"))
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("make sure each character satisfies the predicate"))
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        Some(&self.error)
+    }
+}
+
+/// Checks if each character of the string satisfies the predicate `P`.
+///
+/// Characters of refined strings can be accessed as refined characters
+/// via [`Refinement::chars_refined`].
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, char, str::ForAllChars};
+///
+/// assert!(ForAllChars::<char::Alphabetic>::is_satisfied("nekit"));
+///
+/// let error = ForAllChars::<char::Alphabetic>::check("nekit13").unwrap_err();
+///
+/// assert_eq!((error.index, error.character), (5, '1'));
+/// ```
+pub struct ForAllChars<P: ?Sized> {
+    predicate: PhantomData<P>,
+}
+
+impl<T: AsRef<str> + ?Sized, P: Predicate<char> + ?Sized> Predicate<T> for ForAllChars<P> {
+    type Error = ForAllCharsError<P::Error>;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        value
+            .as_ref()
+            .char_indices()
+            .try_for_each(|(index, character)| {
+                P::check(&character).map_err(|error| Self::Error::new(index, character, error))
+            })
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string where each character is ({})",
+            P::expected()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::for_all_chars<{}>", P::expected_code())
+    }
+}
+
+/// Represents errors that occur when some character does not satisfy the predicate.
+///
+/// This is an alias of [`ForAllCharsError`], which reports the byte index of the failing
+/// character along with the character itself and the error produced by the predicate.
+pub type AllCharsError<E> = ForAllCharsError<E>;
+
+/// Checks if every character of the string satisfies the predicate `P`.
+///
+/// This is an alias of [`ForAllChars`], thus using it with [`chars_refined`] is possible too.
+///
+/// [`chars_refined`]: Refinement::chars_refined
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     char::{ascii::Digit, unicode::Alphabetic},
+///     str::AllChars,
+/// };
+///
+/// assert!(AllChars::<Alphabetic>::is_satisfied("привет"));
+/// assert!(AllChars::<Digit>::is_satisfied("1337"));
+///
+/// let error = AllChars::<Digit>::check("13x7").unwrap_err();
+///
+/// assert_eq!((error.index, error.character), (2, 'x'));
+/// ```
+pub type AllChars<P> = ForAllChars<P>;
+
+/// Represents errors that occur when no character satisfies the predicate.
+#[derive(Debug, Error, Default)]
+#[error("expected string to contain at least one matching character")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::any_char),
+        help("make sure at least one character satisfies the predicate")
+    )
+)]
+pub struct AnyCharError;
+
+impl AnyCharError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if at least one character of the string satisfies the predicate `P`.
+///
+/// This complements [`AllChars`], which requires every character to satisfy `P`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, char::ascii::Digit, str::AnyChar};
+///
+/// assert!(AnyChar::<Digit>::is_satisfied("abc1"));
+/// assert!(!AnyChar::<Digit>::is_satisfied("abc"));
+/// ```
+pub struct AnyChar<P: ?Sized> {
+    predicate: PhantomData<P>,
+}
+
+impl<T: AsRef<str> + ?Sized, P: Predicate<char> + ?Sized> Predicate<T> for AnyChar<P> {
+    type Error = AnyCharError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if value
+            .as_ref()
+            .chars()
+            .any(|character| P::is_satisfied(&character))
+        {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string containing at least one character that is ({})",
+            P::expected()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::any_char<{}>", P::expected_code())
+    }
+}
+
+/// Represents iterators over refined characters of refined strings.
+///
+/// This is returned from [`Refinement::chars_refined`].
+pub struct RefinedChars<'s, P: Predicate<char> + ?Sized> {
+    chars: Chars<'s>,
+    predicate: PhantomData<P>,
+}
+
+impl<'s, P: Predicate<char> + ?Sized> RefinedChars<'s, P> {
+    /// Constructs [`Self`] without checking the characters.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that all characters satisfy the predicate.
+    pub const unsafe fn unchecked(chars: Chars<'s>) -> Self {
+        Self {
+            chars,
+            predicate: PhantomData,
+        }
+    }
+
+    /// Returns the underlying string slice.
+    pub fn as_str(&self) -> &'s str {
+        self.chars.as_str()
+    }
+}
+
+impl<P: Predicate<char> + ?Sized> Iterator for RefinedChars<'_, P> {
+    type Item = Refinement<char, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // SAFETY: all characters satisfy the predicate by construction
+        self.chars
+            .next()
+            .map(|character| unsafe { Refinement::unchecked(character) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+impl<P: Predicate<char> + ?Sized> DoubleEndedIterator for RefinedChars<'_, P> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // SAFETY: all characters satisfy the predicate by construction
+        self.chars
+            .next_back()
+            .map(|character| unsafe { Refinement::unchecked(character) })
+    }
+}
+
+impl<P: Predicate<char> + ?Sized> FusedIterator for RefinedChars<'_, P> {}
+
+impl<T: AsRef<str>, P: Predicate<char> + ?Sized, C: TypeStr + ?Sized>
+    Refinement<T, ForAllChars<P>, C>
+{
+    /// Returns the iterator over the characters of the refined string,
+    /// refined with the predicate `P`.
+    ///
+    /// No checks are needed, as each character is guaranteed to satisfy `P`.
+    /// Plain characters are available via [`str::chars`], thanks to dereferencing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use refinement_types::{Refinement, char, str::ForAllChars};
+    ///
+    /// let name = Refinement::<&str, ForAllChars<char::Alphabetic>>::refine("nekit").unwrap();
+    ///
+    /// let upper: String = name
+    ///     .chars_refined()
+    ///     .map(|character| character.to_ascii_uppercase())
+    ///     .collect();
+    ///
+    /// assert_eq!(upper, "NEKIT");
+    /// ```
+    pub fn chars_refined(&self) -> RefinedChars<'_, P> {
+        // SAFETY: each character satisfies `P`, as the string satisfies `ForAllChars<P>`
+        unsafe { RefinedChars::unchecked(self.get().as_ref().chars()) }
+    }
+}
@@ -1,16 +1,29 @@
 //! Core functionality.
 
-use core::{fmt, marker::PhantomData};
+use core::{any, fmt, marker::PhantomData, str::FromStr};
 
 #[cfg(feature = "diagnostics")]
 use miette::Diagnostic;
 
 use thiserror::Error;
 
-use crate::{core::Predicate, static_str::StaticStr, type_str::TypeStr};
+use crate::{
+    core::{ErrorCore, Predicate},
+    static_str::StaticStr,
+    type_str::TypeStr,
+};
 
 #[cfg(feature = "regex")]
-use crate::type_regex::{StaticRegex, TypeRegex};
+use core::array;
+
+#[cfg(feature = "regex")]
+use crate::core::Refinement;
+
+#[cfg(feature = "regex")]
+use regex::Error as RegexError;
+
+#[cfg(feature = "regex")]
+use crate::type_regex::{Regex, StaticRegex, StaticRegexSet, TypeRegex, TypeRegexSet};
 
 /// Represents errors that occur when the string does not start with [`prefix`].
 ///
@@ -198,6 +211,400 @@ impl<T: AsRef<str> + ?Sized, S: TypeStr + ?Sized> Predicate<T> for Contains<S> {
     }
 }
 
+/// Checks if the string starts with the specified prefix `S`, ignoring ASCII case.
+///
+/// Non-ASCII bytes are compared case-sensitively.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::StartsWithIgnoreCase, type_str};
+///
+/// type_str!(Http = "http://");
+///
+/// assert!(StartsWithIgnoreCase::<Http>::is_satisfied("HTTP://nekit.dev"));
+/// assert!(!StartsWithIgnoreCase::<Http>::is_satisfied("https://nekit.dev"));
+///
+/// type_str!(Uber = "über");
+/// type_str!(Empty = "");
+///
+/// assert!(StartsWithIgnoreCase::<Uber>::is_satisfied("üBER alles"));
+/// assert!(!StartsWithIgnoreCase::<Uber>::is_satisfied("ÜBER alles"));
+/// assert!(StartsWithIgnoreCase::<Empty>::is_satisfied("anything"));
+/// ```
+pub struct StartsWithIgnoreCase<S: TypeStr + ?Sized> {
+    prefix: PhantomData<S>,
+}
+
+impl<S: TypeStr + ?Sized> StartsWithIgnoreCase<S> {
+    /// Returns the expected prefix.
+    pub const fn prefix() -> StaticStr {
+        S::VALUE
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, S: TypeStr + ?Sized> Predicate<T> for StartsWithIgnoreCase<S> {
+    type Error = StartsWithError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let prefix = Self::prefix();
+
+        let bytes = value.as_ref().as_bytes();
+
+        if bytes
+            .get(..prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(prefix.as_bytes()))
+        {
+            Ok(())
+        } else {
+            Err(Self::Error::new(prefix))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string starting with `{prefix}` (ignoring case)",
+            prefix = Self::prefix()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::starts_with_ignore_case")
+    }
+}
+
+/// Checks if the string ends with the specified suffix `S`, ignoring ASCII case.
+///
+/// Non-ASCII bytes are compared case-sensitively.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::EndsWithIgnoreCase, type_str};
+///
+/// type_str!(Rs = ".rs");
+///
+/// assert!(EndsWithIgnoreCase::<Rs>::is_satisfied("MAIN.RS"));
+/// assert!(!EndsWithIgnoreCase::<Rs>::is_satisfied("main.py"));
+/// ```
+pub struct EndsWithIgnoreCase<S: TypeStr + ?Sized> {
+    suffix: PhantomData<S>,
+}
+
+impl<S: TypeStr + ?Sized> EndsWithIgnoreCase<S> {
+    /// Returns the expected suffix.
+    pub const fn suffix() -> StaticStr {
+        S::VALUE
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, S: TypeStr + ?Sized> Predicate<T> for EndsWithIgnoreCase<S> {
+    type Error = EndsWithError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let suffix = Self::suffix();
+
+        let bytes = value.as_ref().as_bytes();
+
+        if bytes
+            .len()
+            .checked_sub(suffix.len())
+            .is_some_and(|start| bytes[start..].eq_ignore_ascii_case(suffix.as_bytes()))
+        {
+            Ok(())
+        } else {
+            Err(Self::Error::new(suffix))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string ending with `{suffix}` (ignoring case)",
+            suffix = Self::suffix()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::ends_with_ignore_case")
+    }
+}
+
+/// Checks if the string contains the specified string `S`, ignoring ASCII case.
+///
+/// Non-ASCII bytes are compared case-sensitively.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::ContainsIgnoreCase, type_str};
+///
+/// type_str!(Nekit = "nekit");
+/// type_str!(Empty = "");
+///
+/// assert!(ContainsIgnoreCase::<Nekit>::is_satisfied("by NeKiT dev"));
+/// assert!(ContainsIgnoreCase::<Empty>::is_satisfied(""));
+/// assert!(!ContainsIgnoreCase::<Nekit>::is_satisfied("by nekłt"));
+/// ```
+pub struct ContainsIgnoreCase<S: TypeStr + ?Sized> {
+    string: PhantomData<S>,
+}
+
+impl<S: TypeStr + ?Sized> ContainsIgnoreCase<S> {
+    /// Returns the expected string.
+    pub const fn string() -> StaticStr {
+        S::VALUE
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, S: TypeStr + ?Sized> Predicate<T> for ContainsIgnoreCase<S> {
+    type Error = ContainsError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let string = Self::string();
+
+        let needle = string.as_bytes();
+
+        if needle.is_empty()
+            || value
+                .as_ref()
+                .as_bytes()
+                .windows(needle.len())
+                .any(|window| window.eq_ignore_ascii_case(needle))
+        {
+            Ok(())
+        } else {
+            Err(Self::Error::new(string))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string containing `{string}` (ignoring case)",
+            string = Self::string()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::contains_ignore_case")
+    }
+}
+
+/// Represents errors that occur when the string is not equal to [`expected`], ignoring ASCII case.
+///
+/// [`expected`]: Self::expected
+#[derive(Debug, Error)]
+#[error("expected string to equal `{expected}` (ignoring case)")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::equals_ignore_case),
+        help("make sure the string equals `{expected}`, ignoring case")
+    )
+)]
+pub struct NotEqualIgnoreCaseError {
+    /// The expected string.
+    pub expected: StaticStr,
+}
+
+impl NotEqualIgnoreCaseError {
+    /// Constructs [`Self`].
+    pub const fn new(expected: StaticStr) -> Self {
+        Self { expected }
+    }
+}
+
+/// Checks if the string equals the specified string `S`, ignoring ASCII case.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::EqualsIgnoreCase, type_str};
+///
+/// type_str!(Yes = "yes");
+///
+/// assert!(EqualsIgnoreCase::<Yes>::is_satisfied("YES"));
+///
+/// let error = EqualsIgnoreCase::<Yes>::check("yess").unwrap_err();
+///
+/// assert_eq!(error.expected, "yes");
+/// ```
+pub struct EqualsIgnoreCase<S: TypeStr + ?Sized> {
+    expected: PhantomData<S>,
+}
+
+impl<S: TypeStr + ?Sized> EqualsIgnoreCase<S> {
+    /// Returns the expected string.
+    pub const fn expected() -> StaticStr {
+        S::VALUE
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, S: TypeStr + ?Sized> Predicate<T> for EqualsIgnoreCase<S> {
+    type Error = NotEqualIgnoreCaseError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let expected = Self::expected();
+
+        if value.as_ref().eq_ignore_ascii_case(expected) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(expected))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string equal to `{expected}` (ignoring case)",
+            expected = Self::expected()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::equals_ignore_case")
+    }
+}
+
+/// Represents errors that occur when the string does not contain the required string
+/// or contains the forbidden one.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+pub enum RequiresAndForbidsError {
+    /// The required string is missing.
+    #[error("expected string to contain `{required}` and not `{forbidden}`, missing `{required}`")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::requires_and_forbids::missing),
+            help("make sure the string contains `{required}`")
+        )
+    )]
+    Missing {
+        /// The required string.
+        required: StaticStr,
+        /// The forbidden string.
+        forbidden: StaticStr,
+    },
+    /// The forbidden string is present.
+    #[error("expected string to contain `{required}` and not `{forbidden}`, found `{forbidden}`")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::requires_and_forbids::present),
+            help("make sure the string does not contain `{forbidden}`")
+        )
+    )]
+    Present {
+        /// The required string.
+        required: StaticStr,
+        /// The forbidden string.
+        forbidden: StaticStr,
+    },
+}
+
+impl RequiresAndForbidsError {
+    /// Constructs [`Self::Missing`].
+    pub const fn missing(required: StaticStr, forbidden: StaticStr) -> Self {
+        Self::Missing {
+            required,
+            forbidden,
+        }
+    }
+
+    /// Constructs [`Self::Present`].
+    pub const fn present(required: StaticStr, forbidden: StaticStr) -> Self {
+        Self::Present {
+            required,
+            forbidden,
+        }
+    }
+}
+
+/// Checks if the string contains the required string `R` and does not contain
+/// the forbidden string `F`.
+///
+/// This is similar to `And<Contains<R>, Not<Contains<F>>>`,
+/// except that errors name both strings.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     str::{RequiresAndForbids, core::RequiresAndForbidsError},
+///     type_str,
+/// };
+///
+/// type_str!(Required = "https://");
+/// type_str!(Forbidden = "@");
+///
+/// type Link = RequiresAndForbids<Required, Forbidden>;
+///
+/// assert!(Link::is_satisfied("https://nekit.dev/"));
+///
+/// let error = Link::check("http://nekit.dev/").unwrap_err();
+///
+/// assert!(matches!(error, RequiresAndForbidsError::Missing { .. }));
+///
+/// let error = Link::check("https://user@nekit.dev/").unwrap_err();
+///
+/// assert!(matches!(error, RequiresAndForbidsError::Present { .. }));
+/// ```
+pub struct RequiresAndForbids<R: TypeStr + ?Sized, F: TypeStr + ?Sized> {
+    required: PhantomData<R>,
+    forbidden: PhantomData<F>,
+}
+
+impl<R: TypeStr + ?Sized, F: TypeStr + ?Sized> RequiresAndForbids<R, F> {
+    /// Returns the required string.
+    pub const fn required() -> StaticStr {
+        R::VALUE
+    }
+
+    /// Returns the forbidden string.
+    pub const fn forbidden() -> StaticStr {
+        F::VALUE
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, R: TypeStr + ?Sized, F: TypeStr + ?Sized> Predicate<T>
+    for RequiresAndForbids<R, F>
+{
+    type Error = RequiresAndForbidsError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let string = value.as_ref();
+
+        let required = Self::required();
+        let forbidden = Self::forbidden();
+
+        if !string.contains(required) {
+            Err(Self::Error::missing(required, forbidden))
+        } else if string.contains(forbidden) {
+            Err(Self::Error::present(required, forbidden))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string containing `{required}` and not `{forbidden}`",
+            required = Self::required(),
+            forbidden = Self::forbidden()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::requires_and_forbids")
+    }
+}
+
 /// Represents errors that occur when the string does not start with [`start`] character.
 ///
 /// [`start`]: Self::start
@@ -528,55 +935,401 @@ impl<T: AsRef<str> + ?Sized> Predicate<T> for Ascii {
     }
 }
 
-/// Represents errors that occur when the string does not match the expected [`pattern`].
-///
-/// [`pattern`]: Self::pattern
-#[cfg(feature = "regex")]
-#[derive(Debug, Error)]
-#[error("received string that does not match the `{pattern}` pattern")]
+/// Represents errors that occur when the string contains both uppercase
+/// and lowercase ASCII letters.
+#[derive(Debug, Error, Default)]
+#[error("expected string to not mix ascii letter cases")]
 #[cfg_attr(
     feature = "diagnostics",
     derive(Diagnostic),
     diagnostic(
-        code(str::matches),
-        help("make sure the string matches the `{pattern}` pattern")
+        code(str::uniform_case),
+        help("make sure the string letters are either all uppercase or all lowercase")
     )
 )]
-pub struct MismatchError {
-    /// The expected pattern.
-    pub pattern: StaticStr,
-}
+pub struct UniformAsciiCaseError;
 
-#[cfg(feature = "regex")]
-impl MismatchError {
+impl UniformAsciiCaseError {
     /// Constructs [`Self`].
-    pub const fn new(pattern: StaticStr) -> Self {
-        Self { pattern }
+    pub const fn new() -> Self {
+        Self
     }
 }
 
-/// Checks if the string matches the specified pattern `S`.
-#[cfg(feature = "regex")]
-pub struct Matches<S: TypeRegex + ?Sized> {
-    pattern: PhantomData<S>,
-}
-
-#[cfg(feature = "regex")]
-impl<S: TypeRegex + ?Sized> Matches<S> {
-    /// Returns the expected regular expression.
-    pub fn regex() -> StaticRegex {
-        S::get()
-    }
+/// Checks if the string letters are either all ASCII uppercase or all ASCII lowercase.
+///
+/// Strings without ASCII letters are always accepted.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::UniformAsciiCase};
+///
+/// assert!(UniformAsciiCase::is_satisfied("ABC"));
+/// assert!(UniformAsciiCase::is_satisfied("abc"));
+/// assert!(UniformAsciiCase::is_satisfied("1337"));
+///
+/// assert!(!UniformAsciiCase::is_satisfied("Abc"));
+/// ```
+pub struct UniformAsciiCase {
+    private: PhantomData<()>,
 }
 
-#[cfg(feature = "regex")]
-impl<T: AsRef<str> + ?Sized, S: TypeRegex + ?Sized> Predicate<T> for Matches<S> {
-    type Error = MismatchError;
+impl<T: AsRef<str> + ?Sized> Predicate<T> for UniformAsciiCase {
+    type Error = UniformAsciiCaseError;
 
     fn check(value: &T) -> Result<(), Self::Error> {
-        let regex = Self::regex();
+        let bytes = value.as_ref().as_bytes();
 
-        if regex.is_match(value.as_ref()) {
+        let uppercase = bytes.iter().any(u8::is_ascii_uppercase);
+        let lowercase = bytes.iter().any(u8::is_ascii_lowercase);
+
+        if uppercase && lowercase {
+            Err(Self::Error::new())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("string with uniform ascii case")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::uniform_case")
+    }
+}
+
+/// Represents errors that occur when the string contains whitespace.
+#[derive(Debug, Error)]
+#[error("received whitespace {character:?} at offset {offset}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::no_whitespace),
+        help("make sure the string does not contain whitespace")
+    )
+)]
+pub struct NoWhitespaceError {
+    /// The byte offset of the whitespace character.
+    pub offset: usize,
+    /// The whitespace character.
+    pub character: char,
+}
+
+impl NoWhitespaceError {
+    /// Constructs [`Self`].
+    pub const fn new(offset: usize, character: char) -> Self {
+        Self { offset, character }
+    }
+}
+
+/// Checks if the string does not contain whitespace, as per [`char::is_whitespace`].
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::NoWhitespace};
+///
+/// assert!(NoWhitespace::is_satisfied("nekit"));
+///
+/// let error = NoWhitespace::check("nek\tit").unwrap_err();
+///
+/// assert_eq!((error.offset, error.character), (3, '\t'));
+///
+/// let error = NoWhitespace::check("nekit\n").unwrap_err();
+///
+/// assert_eq!((error.offset, error.character), (5, '\n'));
+/// ```
+pub struct NoWhitespace {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for NoWhitespace {
+    type Error = NoWhitespaceError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        value
+            .as_ref()
+            .char_indices()
+            .find(|(_, character)| character.is_whitespace())
+            .map_or(Ok(()), |(offset, character)| {
+                Err(Self::Error::new(offset, character))
+            })
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("string without whitespace")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::no_whitespace")
+    }
+}
+
+/// Represents errors that occur when the string contains control characters.
+#[derive(Debug, Error)]
+#[error("received control character {character:?} at offset {offset}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::no_control),
+        help("make sure the string does not contain control characters")
+    )
+)]
+pub struct NoControlError {
+    /// The byte offset of the control character.
+    pub offset: usize,
+    /// The control character.
+    pub character: char,
+}
+
+impl NoControlError {
+    /// Constructs [`Self`].
+    pub const fn new(offset: usize, character: char) -> Self {
+        Self { offset, character }
+    }
+}
+
+/// Checks if the string does not contain control characters, as per [`char::is_control`].
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::NoControl};
+///
+/// assert!(NoControl::is_satisfied("nekit dev"));
+///
+/// let error = NoControl::check("nek\0it").unwrap_err();
+///
+/// assert_eq!((error.offset, error.character), (3, '\0'));
+///
+/// let error = NoControl::check("\tnekit").unwrap_err();
+///
+/// assert_eq!((error.offset, error.character), (0, '\t'));
+/// ```
+pub struct NoControl {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for NoControl {
+    type Error = NoControlError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        value
+            .as_ref()
+            .char_indices()
+            .find(|(_, character)| character.is_control())
+            .map_or(Ok(()), |(offset, character)| {
+                Err(Self::Error::new(offset, character))
+            })
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("string without control characters")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::no_control")
+    }
+}
+
+/// Represents errors that occur when the string is not lowercase.
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[derive(Debug, Error, Default)]
+#[error("expected string to be lowercase")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(code(str::lowercase), help("make sure the string is lowercase"))
+)]
+pub struct LowercaseError;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl LowercaseError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the string is equal to its Unicode lowercase form, as per [`str::to_lowercase`].
+///
+/// Unlike [`UniformAsciiCase`], this predicate handles non-ASCII letters too.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::Lowercase};
+///
+/// assert!(Lowercase::is_satisfied("abc"));
+/// assert!(Lowercase::is_satisfied("straße"));
+///
+/// assert!(!Lowercase::is_satisfied("ABC"));
+/// assert!(!Lowercase::is_satisfied("\u{130}"));
+/// ```
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub struct Lowercase {
+    private: PhantomData<()>,
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T: AsRef<str> + ?Sized> Predicate<T> for Lowercase {
+    type Error = LowercaseError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let string = value.as_ref();
+
+        if string == string.to_lowercase() {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("lowercase string")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::lowercase")
+    }
+}
+
+/// Represents errors that occur when the string can not be parsed.
+#[derive(Debug)]
+pub struct ParseError<E> {
+    /// The error produced by parsing.
+    pub error: E,
+}
+
+impl<E> ParseError<E> {
+    /// Constructs [`Self`].
+    pub const fn new(error: E) -> Self {
+        Self { error }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "failed to parse string: {error}",
+            error = self.error
+        )
+    }
+}
+
+impl<E: ErrorCore + 'static> ErrorCore for ParseError<E> {
+    fn source(&self) -> Option<&(dyn ErrorCore + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+impl<E: ErrorCore + 'static> Diagnostic for ParseError<E> {
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("str::parsable_as"))
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("make sure the string can be parsed"))
+    }
+}
+
+/// Checks if the string can be parsed as `N`, as per [`str::parse`].
+///
+/// For integers, this checks both the format and the range of the value.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::ParsableAs};
+///
+/// assert!(ParsableAs::<u8>::is_satisfied("255"));
+///
+/// assert!(!ParsableAs::<u8>::is_satisfied("256"));
+/// assert!(!ParsableAs::<u8>::is_satisfied("abc"));
+///
+/// let error = ParsableAs::<i32>::check("nekit").unwrap_err();
+///
+/// assert_eq!(error.error, "nekit".parse::<i32>().unwrap_err());
+/// ```
+pub struct ParsableAs<N: ?Sized> {
+    parsed: PhantomData<N>,
+}
+
+impl<T: AsRef<str> + ?Sized, N: FromStr> Predicate<T> for ParsableAs<N> {
+    type Error = ParseError<N::Err>;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        value
+            .as_ref()
+            .parse::<N>()
+            .map(|_| ())
+            .map_err(Self::Error::new)
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "string parsable as `{}`", any::type_name::<N>())
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::parsable_as<{}>", any::type_name::<N>())
+    }
+}
+
+/// Represents errors that occur when the string does not match the expected [`pattern`].
+///
+/// [`pattern`]: Self::pattern
+#[cfg(feature = "regex")]
+#[derive(Debug, Error)]
+#[error("received string that does not match the `{pattern}` pattern")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::matches),
+        help("make sure the string matches the `{pattern}` pattern")
+    )
+)]
+pub struct MismatchError {
+    /// The expected pattern.
+    pub pattern: StaticStr,
+}
+
+#[cfg(feature = "regex")]
+impl MismatchError {
+    /// Constructs [`Self`].
+    pub const fn new(pattern: StaticStr) -> Self {
+        Self { pattern }
+    }
+}
+
+/// Checks if the string matches the specified pattern `S`.
+#[cfg(feature = "regex")]
+pub struct Matches<S: TypeRegex + ?Sized> {
+    pattern: PhantomData<S>,
+}
+
+#[cfg(feature = "regex")]
+impl<S: TypeRegex + ?Sized> Matches<S> {
+    /// Returns the expected regular expression.
+    pub fn regex() -> StaticRegex {
+        S::get()
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<T: AsRef<str> + ?Sized, S: TypeRegex + ?Sized> Predicate<T> for Matches<S> {
+    type Error = MismatchError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let regex = Self::regex();
+
+        if regex.is_match(value.as_ref()) {
             Ok(())
         } else {
             Err(Self::Error::new(regex.as_str()))
@@ -595,3 +1348,359 @@ impl<T: AsRef<str> + ?Sized, S: TypeRegex + ?Sized> Predicate<T> for Matches<S>
         formatter.write_str("str::matches")
     }
 }
+
+/// Checks if the string matches the specified pattern `S` entirely.
+///
+/// Unlike [`Matches`], which accepts matches anywhere in the string,
+/// this predicate requires some match to span the whole string.
+///
+/// This is checked against the pattern anchored as `\A(?:...)\z` (see [`get_anchored`]),
+/// so `a|ab` fully matches `ab` as well.
+///
+/// [`get_anchored`]: TypeRegex::get_anchored
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     str::{FullMatch, Matches},
+///     type_regex,
+/// };
+///
+/// type_regex!(Digits = "[0-9]+");
+///
+/// assert!(Matches::<Digits>::is_satisfied("a1b"));
+/// assert!(!FullMatch::<Digits>::is_satisfied("a1b"));
+///
+/// assert!(FullMatch::<Digits>::is_satisfied("1337"));
+///
+/// type_regex!(Alternatives = "a|ab");
+///
+/// assert!(FullMatch::<Alternatives>::is_satisfied("ab"));
+/// assert!(!FullMatch::<Alternatives>::is_satisfied("abc"));
+/// ```
+#[cfg(feature = "regex")]
+pub struct FullMatch<S: TypeRegex + ?Sized> {
+    pattern: PhantomData<S>,
+}
+
+#[cfg(feature = "regex")]
+impl<S: TypeRegex + ?Sized> FullMatch<S> {
+    /// Returns the expected regular expression.
+    pub fn regex() -> StaticRegex {
+        S::get()
+    }
+
+    /// Returns the expected regular expression, anchored to match the whole string.
+    pub fn anchored() -> StaticRegex {
+        S::get_anchored()
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<T: AsRef<str> + ?Sized, S: TypeRegex + ?Sized> Predicate<T> for FullMatch<S> {
+    type Error = MismatchError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if Self::anchored().is_match(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(Self::regex().as_str()))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string fully matching the `{pattern}` pattern",
+            pattern = Self::regex().as_str()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::full_match")
+    }
+}
+
+/// Represents errors that occur when the string does not match any of the expected [`patterns`].
+///
+/// [`patterns`]: Self::patterns
+#[cfg(feature = "regex")]
+#[derive(Debug, Error)]
+#[error("received string that does not match any of the {patterns:?} patterns")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::matches_any),
+        help("make sure the string matches any of the {patterns:?} patterns")
+    )
+)]
+pub struct MismatchAnyError {
+    /// The expected patterns.
+    pub patterns: &'static [String],
+}
+
+#[cfg(feature = "regex")]
+impl MismatchAnyError {
+    /// Constructs [`Self`].
+    pub const fn new(patterns: &'static [String]) -> Self {
+        Self { patterns }
+    }
+}
+
+/// Checks if the string matches any of the patterns in the specified set `S`.
+///
+/// This is more efficient than combining multiple [`Matches`] predicates,
+/// as all patterns are matched at once.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::MatchesAny, type_regex, type_regex_set};
+///
+/// type_regex!(Integer = "^(0|[1-9][0-9]*)$");
+/// type_regex!(Hexadecimal = "^0x[0-9a-f]+$");
+/// type_regex!(Binary = "^0b[01]+$");
+///
+/// type_regex_set!(Number = [Integer, Hexadecimal, Binary]);
+///
+/// assert!(MatchesAny::<Number>::is_satisfied("42"));
+/// assert!(MatchesAny::<Number>::is_satisfied("0xff"));
+/// assert!(MatchesAny::<Number>::is_satisfied("0b101"));
+///
+/// let error = MatchesAny::<Number>::check("nekit").unwrap_err();
+///
+/// assert_eq!(error.patterns.len(), 3);
+/// ```
+#[cfg(feature = "regex")]
+pub struct MatchesAny<S: TypeRegexSet + ?Sized> {
+    patterns: PhantomData<S>,
+}
+
+#[cfg(feature = "regex")]
+impl<S: TypeRegexSet + ?Sized> MatchesAny<S> {
+    /// Returns the expected regular expression set.
+    pub fn regex_set() -> StaticRegexSet {
+        S::get()
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<T: AsRef<str> + ?Sized, S: TypeRegexSet + ?Sized> Predicate<T> for MatchesAny<S> {
+    type Error = MismatchAnyError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let regex_set = Self::regex_set();
+
+        if regex_set.is_match(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(regex_set.patterns()))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string matching any of the {patterns:?} patterns",
+            patterns = Self::regex_set().patterns()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::matches_any")
+    }
+}
+
+/// Represents errors that occur when the string does not match the expected [`pattern`]
+/// with exactly [`groups`] capture groups.
+///
+/// [`pattern`]: Self::pattern
+/// [`groups`]: Self::groups
+#[cfg(feature = "regex")]
+#[derive(Debug, Error)]
+#[error("received string that does not match the `{pattern}` pattern with {groups} groups")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::matches_with_groups),
+        help("make sure the string matches the `{pattern}` pattern with {groups} groups")
+    )
+)]
+pub struct GroupsMismatchError {
+    /// The expected pattern.
+    pub pattern: StaticStr,
+    /// The expected number of capture groups (the `N`).
+    pub groups: usize,
+}
+
+#[cfg(feature = "regex")]
+impl GroupsMismatchError {
+    /// Constructs [`Self`].
+    pub const fn new(pattern: StaticStr, groups: usize) -> Self {
+        Self { pattern, groups }
+    }
+}
+
+/// Checks if the string matches the specified pattern `S`,
+/// producing exactly `N` capture groups.
+///
+/// The pattern must have exactly `N` capture groups (excluding the implicit one),
+/// and each of them must participate in the match.
+///
+/// The captures can then be accessed infallibly via [`Refinement::groups`].
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, Refinement, str::MatchesWithGroups, type_regex};
+///
+/// type_regex!(Date = r"^(\d{4})-(\d{2})-(\d{2})$");
+///
+/// type DateString<'s> = Refinement<&'s str, MatchesWithGroups<Date, 3>>;
+///
+/// let date = DateString::refine("2025-05-13").unwrap();
+///
+/// assert_eq!(date.groups(), ["2025", "05", "13"]);
+///
+/// assert!(!MatchesWithGroups::<Date, 3>::is_satisfied("2025-05"));
+/// assert!(!MatchesWithGroups::<Date, 2>::is_satisfied("2025-05-13"));
+/// ```
+#[cfg(feature = "regex")]
+pub struct MatchesWithGroups<S: TypeRegex + ?Sized, const N: usize> {
+    pattern: PhantomData<S>,
+}
+
+#[cfg(feature = "regex")]
+impl<S: TypeRegex + ?Sized, const N: usize> MatchesWithGroups<S, N> {
+    /// Returns the expected regular expression.
+    pub fn regex() -> StaticRegex {
+        S::get()
+    }
+
+    fn matches(string: &str) -> bool {
+        let regex = Self::regex();
+
+        regex.captures_len() == N + 1
+            && regex
+                .captures(string)
+                .is_some_and(|captures| captures.iter().all(|group| group.is_some()))
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<T: AsRef<str> + ?Sized, S: TypeRegex + ?Sized, const N: usize> Predicate<T>
+    for MatchesWithGroups<S, N>
+{
+    type Error = GroupsMismatchError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if Self::matches(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(Self::regex().as_str(), N))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string matching the `{pattern}` pattern with {N} groups",
+            pattern = Self::regex().as_str()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::matches_with_groups<{N}>")
+    }
+}
+
+/// The message used in case the refined string does not match, which never happens.
+#[cfg(feature = "regex")]
+const MATCHED: StaticStr = "the refined string is guaranteed to match";
+
+#[cfg(feature = "regex")]
+impl<T: AsRef<str>, S: TypeRegex + ?Sized, const N: usize, C: TypeStr + ?Sized>
+    Refinement<T, MatchesWithGroups<S, N>, C>
+{
+    /// Returns the capture groups of the refined string.
+    ///
+    /// # Panics
+    ///
+    /// This method never panics, since the refined string is guaranteed to match
+    /// the pattern with exactly `N` capture groups.
+    pub fn groups(&self) -> [&str; N] {
+        let captures = MatchesWithGroups::<S, N>::regex()
+            .captures(self.get().as_ref())
+            .expect(MATCHED);
+
+        array::from_fn(|index| captures.get(index + 1).expect(MATCHED).as_str())
+    }
+}
+
+/// Represents errors that occur when the string is not a valid regular expression.
+#[cfg(feature = "regex")]
+#[derive(Debug, Error)]
+#[error("received invalid regular expression")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::valid_regex),
+        help("make sure the string is a valid regular expression")
+    )
+)]
+pub struct InvalidRegexError {
+    /// The error produced when compiling the regular expression.
+    #[source]
+    pub error: RegexError,
+}
+
+#[cfg(feature = "regex")]
+impl InvalidRegexError {
+    /// Constructs [`Self`].
+    pub const fn new(error: RegexError) -> Self {
+        Self { error }
+    }
+}
+
+/// Checks if the string is a valid regular expression, that is, whether it compiles.
+///
+/// Note that the string is compiled on every check, as the pattern is not known statically.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::ValidRegex};
+///
+/// assert!(ValidRegex::is_satisfied(".*"));
+/// assert!(ValidRegex::is_satisfied(r"^(?<year>\d{4})-(?<month>\d{2})-(?<day>\d{2})$"));
+///
+/// assert!(!ValidRegex::is_satisfied("("));
+/// ```
+#[cfg(feature = "regex")]
+pub struct ValidRegex {
+    private: PhantomData<()>,
+}
+
+#[cfg(feature = "regex")]
+impl<T: AsRef<str> + ?Sized> Predicate<T> for ValidRegex {
+    type Error = InvalidRegexError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        Regex::new(value.as_ref())
+            .map(|_| ())
+            .map_err(Self::Error::new)
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("valid regular expression")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::valid_regex")
+    }
+}
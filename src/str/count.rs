@@ -0,0 +1,138 @@
+//! Predicates based on counting substrings.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use crate::{
+    core::{ErrorCore, Predicate},
+    int::usize::{Equal, GreaterOrEqual, LessOrEqual},
+    static_str::StaticStr,
+    type_str::TypeStr,
+};
+
+/// Represents errors that occur when the number of occurrences does not satisfy the predicate.
+#[derive(Debug)]
+pub struct SubstringCountError<E> {
+    /// The counted substring.
+    pub string: StaticStr,
+    /// The number of occurrences.
+    pub count: usize,
+    /// The error produced by the predicate.
+    pub error: E,
+}
+
+impl<E> SubstringCountError<E> {
+    /// Constructs [`Self`].
+    pub const fn new(string: StaticStr, count: usize, error: E) -> Self {
+        Self {
+            string,
+            count,
+            error,
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for SubstringCountError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "error on {count} occurrences of `{string}`: {error}",
+            count = self.count,
+            string = self.string,
+            error = self.error
+        )
+    }
+}
+
+impl<E: ErrorCore + 'static> ErrorCore for SubstringCountError<E> {
+    fn source(&self) -> Option<&(dyn ErrorCore + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+impl<E: Diagnostic + 'static> Diagnostic for SubstringCountError<E> {
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("str::substring_count"))
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new(
+            "make sure the number of occurrences satisfies the predicate",
+        ))
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        Some(&self.error)
+    }
+}
+
+/// Checks if the number of non-overlapping occurrences of `S` in the string
+/// satisfies the predicate `P`.
+///
+/// Occurrences are counted as per [`str::matches`]; note that empty strings
+/// occur before and after every character.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, int::usize, str::count::SubstringCount, type_str};
+///
+/// type_str!(Ab = "ab");
+///
+/// assert!(SubstringCount::<Ab, usize::Equal<3>>::is_satisfied("ababab"));
+/// assert!(SubstringCount::<Ab, usize::LessOrEqual<3>>::is_satisfied("ababab"));
+///
+/// let error = SubstringCount::<Ab, usize::Less<3>>::check("ababab").unwrap_err();
+///
+/// assert_eq!(error.count, 3);
+/// ```
+pub struct SubstringCount<S: TypeStr + ?Sized, P: ?Sized> {
+    string: PhantomData<S>,
+    predicate: PhantomData<P>,
+}
+
+impl<S: TypeStr + ?Sized, P: ?Sized> SubstringCount<S, P> {
+    /// Returns the counted substring.
+    pub const fn string() -> StaticStr {
+        S::VALUE
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, S: TypeStr + ?Sized, P: Predicate<usize> + ?Sized> Predicate<T>
+    for SubstringCount<S, P>
+{
+    type Error = SubstringCountError<P::Error>;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let string = Self::string();
+
+        let count = value.as_ref().matches(string).count();
+
+        P::check(&count).map_err(|error| Self::Error::new(string, count, error))
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string where the number of `{string}` occurrences is ({expected})",
+            string = Self::string(),
+            expected = P::expected()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::substring_count<{}>", P::expected_code())
+    }
+}
+
+/// Checks if the string contains at most `N` non-overlapping occurrences of `S`.
+pub type SubstringCountAtMost<S, const N: usize> = SubstringCount<S, LessOrEqual<N>>;
+
+/// Checks if the string contains at least `N` non-overlapping occurrences of `S`.
+pub type SubstringCountAtLeast<S, const N: usize> = SubstringCount<S, GreaterOrEqual<N>>;
+
+/// Checks if the string contains exactly `N` non-overlapping occurrences of `S`.
+pub type SubstringCountExactly<S, const N: usize> = SubstringCount<S, Equal<N>>;
@@ -0,0 +1,215 @@
+//! Predicates based on cron expressions.
+
+use core::{fmt, marker::PhantomData, ops::RangeInclusive};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::{core::Predicate, static_str::StaticStr};
+
+/// The number of fields in cron expressions.
+pub const FIELDS: usize = 5;
+
+/// Represents fields of cron expressions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CronField {
+    /// The minute field, `0-59`.
+    Minute,
+    /// The hour field, `0-23`.
+    Hour,
+    /// The day of month field, `1-31`.
+    Day,
+    /// The month field, `1-12`.
+    Month,
+    /// The day of week field, `0-7`, where both `0` and `7` are Sunday.
+    Weekday,
+}
+
+impl CronField {
+    /// All fields, in order.
+    pub const ALL: [Self; FIELDS] = [
+        Self::Minute,
+        Self::Hour,
+        Self::Day,
+        Self::Month,
+        Self::Weekday,
+    ];
+
+    /// Returns the name of the field.
+    pub const fn name(self) -> StaticStr {
+        match self {
+            Self::Minute => "minute",
+            Self::Hour => "hour",
+            Self::Day => "day",
+            Self::Month => "month",
+            Self::Weekday => "weekday",
+        }
+    }
+
+    /// Returns the range of values of the field.
+    pub const fn range(self) -> RangeInclusive<u8> {
+        match self {
+            Self::Minute => 0..=59,
+            Self::Hour => 0..=23,
+            Self::Day => 1..=31,
+            Self::Month => 1..=12,
+            Self::Weekday => 0..=7,
+        }
+    }
+
+    fn parse_value(self, string: &str) -> Option<u8> {
+        if string.is_empty() || !string.bytes().all(|byte| byte.is_ascii_digit()) {
+            return None;
+        }
+
+        string
+            .parse()
+            .ok()
+            .filter(|value| self.range().contains(value))
+    }
+
+    fn is_step(string: &str) -> bool {
+        !string.is_empty()
+            && string.bytes().all(|byte| byte.is_ascii_digit())
+            && string.parse::<u8>().is_ok_and(|step| step > 0)
+    }
+
+    fn is_base(self, string: &str) -> bool {
+        if string == "*" {
+            return true;
+        }
+
+        match string.split_once('-') {
+            Some((start, end)) => self
+                .parse_value(start)
+                .zip(self.parse_value(end))
+                .is_some_and(|(start, end)| start <= end),
+            None => self.parse_value(string).is_some(),
+        }
+    }
+
+    fn is_item(self, string: &str) -> bool {
+        match string.split_once('/') {
+            Some((base, step)) => self.is_base(base) && Self::is_step(step),
+            None => self.is_base(string),
+        }
+    }
+
+    /// Checks whether the given string is valid for the field.
+    ///
+    /// Fields are comma-separated lists of items, where each item is either `*`,
+    /// a value or an inclusive range of values `a-b`, optionally followed by a step `/n`.
+    pub fn is_valid(self, string: &str) -> bool {
+        string.split(',').all(|item| self.is_item(item))
+    }
+}
+
+impl fmt::Display for CronField {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
+
+/// Represents errors that occur when the string is not a valid cron expression.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+pub enum CronError {
+    /// The expression does not have exactly five fields.
+    #[error("expected {FIELDS} fields in cron expression, received {count}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::cron::count),
+            help("make sure the expression has minute, hour, day, month and weekday fields")
+        )
+    )]
+    Count {
+        /// The received number of fields.
+        count: usize,
+    },
+    /// Some field of the expression is invalid.
+    #[error("received invalid {field} field in cron expression")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(code(str::cron::field), help("make sure the {field} field is valid"))
+    )]
+    Field {
+        /// The invalid field.
+        field: CronField,
+    },
+}
+
+impl CronError {
+    /// Constructs [`Self::Count`].
+    pub const fn count(count: usize) -> Self {
+        Self::Count { count }
+    }
+
+    /// Constructs [`Self::Field`].
+    pub const fn field(field: CronField) -> Self {
+        Self::Field { field }
+    }
+}
+
+/// Checks if the string is a valid 5-field cron expression.
+///
+/// The fields are minute, hour, day of month, month and day of week, separated by whitespace.
+/// Each field supports wildcards (`*`), values, ranges (`a-b`), steps (`/n`) and lists (`,`).
+/// Names of months and days of week are not supported.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     str::{
+///         Cron,
+///         cron::{CronError, CronField},
+///     },
+/// };
+///
+/// assert!(Cron::is_satisfied("*/5 * * * *"));
+/// assert!(Cron::is_satisfied("0 0 * * 0"));
+/// assert!(Cron::is_satisfied("0,30 9-17 1-15/2 * 1-5"));
+///
+/// let error = Cron::check("60 * * * *").unwrap_err();
+///
+/// assert!(matches!(error, CronError::Field { field: CronField::Minute }));
+///
+/// let error = Cron::check("* * * *").unwrap_err();
+///
+/// assert!(matches!(error, CronError::Count { count: 4 }));
+/// ```
+pub struct Cron {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for Cron {
+    type Error = CronError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let string = value.as_ref();
+
+        let count = string.split_ascii_whitespace().count();
+
+        if count != FIELDS {
+            return Err(Self::Error::count(count));
+        }
+
+        string
+            .split_ascii_whitespace()
+            .zip(CronField::ALL)
+            .find(|&(part, field)| !field.is_valid(part))
+            .map_or(Ok(()), |(_, field)| Err(Self::Error::field(field)))
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("cron expression")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::cron")
+    }
+}
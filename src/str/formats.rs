@@ -0,0 +1,1453 @@
+//! Predicates based on string formats.
+
+use core::{fmt, marker::PhantomData, ops::RangeInclusive};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::{core::Predicate, static_str::StaticStr, type_str::TypeStr};
+
+/// Checks whether the given string is non-empty and consists of ASCII digits only.
+fn is_digits(string: &str) -> bool {
+    !string.is_empty() && string.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// Represents errors that occur when the string is not a decimal number
+/// with at most [`scale`] fractional digits.
+///
+/// [`scale`]: Self::scale
+#[derive(Debug, Error)]
+#[error("expected decimal with at most {scale} fractional digits")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::decimal),
+        help("make sure the string is a decimal with at most {scale} fractional digits")
+    )
+)]
+pub struct DecimalError {
+    /// The maximum number of fractional digits (the `N`).
+    pub scale: usize,
+}
+
+impl DecimalError {
+    /// Constructs [`Self`].
+    pub const fn new(scale: usize) -> Self {
+        Self { scale }
+    }
+}
+
+/// Checks if the string is a finite decimal number with at most `N` fractional digits.
+///
+/// The accepted format is an optional sign (`+` or `-`), followed by integer digits,
+/// optionally followed by `.` and fractional digits. Exponents are not allowed.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::Decimal};
+///
+/// assert!(Decimal::<2>::is_satisfied("12.34"));
+/// assert!(Decimal::<2>::is_satisfied("-0.5"));
+///
+/// assert!(!Decimal::<2>::is_satisfied("12.345"));
+/// assert!(!Decimal::<2>::is_satisfied("1e3"));
+/// ```
+pub struct Decimal<const N: usize> {
+    private: PhantomData<()>,
+}
+
+impl<const N: usize> Decimal<N> {
+    fn is_decimal(string: &str) -> bool {
+        let unsigned = string.strip_prefix(['+', '-']).unwrap_or(string);
+
+        match unsigned.split_once('.') {
+            Some((integer, fraction)) => {
+                is_digits(integer) && is_digits(fraction) && fraction.len() <= N
+            }
+            None => is_digits(unsigned),
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, const N: usize> Predicate<T> for Decimal<N> {
+    type Error = DecimalError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if Self::is_decimal(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(N))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "decimal with at most {N} fractional digits")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::decimal<{N}>")
+    }
+}
+
+/// Represents errors that occur when the string is not a valid environment variable name.
+#[derive(Debug, Error, Default)]
+#[error("expected valid environment variable name")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::env_var_name),
+        help("make sure the string matches `[A-Za-z_][A-Za-z0-9_]*`")
+    )
+)]
+pub struct EnvVarNameError;
+
+impl EnvVarNameError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the string is a valid POSIX environment variable name.
+///
+/// Such names are non-empty, consist of ASCII letters, digits and underscores,
+/// and do not start with digits.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::EnvVarName};
+///
+/// assert!(EnvVarName::is_satisfied("PATH"));
+/// assert!(EnvVarName::is_satisfied("MY_VAR"));
+///
+/// assert!(!EnvVarName::is_satisfied("1VAR"));
+/// assert!(!EnvVarName::is_satisfied(""));
+/// ```
+pub struct EnvVarName {
+    private: PhantomData<()>,
+}
+
+impl EnvVarName {
+    fn is_env_var_name(string: &str) -> bool {
+        let mut bytes = string.bytes();
+
+        bytes
+            .next()
+            .is_some_and(|byte| byte.is_ascii_alphabetic() || byte == b'_')
+            && bytes.all(|byte| byte.is_ascii_alphanumeric() || byte == b'_')
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for EnvVarName {
+    type Error = EnvVarNameError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if Self::is_env_var_name(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("environment variable name")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::env_var_name")
+    }
+}
+
+/// Checks whether the given character requires quoting in CSV fields.
+const fn requires_quoting(character: char) -> bool {
+    matches!(character, ',' | '"' | '\r' | '\n')
+}
+
+/// Represents errors that occur when the string can not be written as unquoted CSV field.
+#[derive(Debug, Error, Default)]
+#[error("expected string to not contain commas, quotes or line breaks")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::csv_field),
+        help("make sure the string does not contain commas, quotes or line breaks")
+    )
+)]
+pub struct CsvFieldError;
+
+impl CsvFieldError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the string can be written as unquoted CSV field,
+/// that is, whether it contains no commas, quotes, carriage returns or line feeds.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::CsvField};
+///
+/// assert!(CsvField::is_satisfied("plain"));
+///
+/// assert!(!CsvField::is_satisfied("a,b"));
+/// assert!(!CsvField::is_satisfied("with\"quote"));
+/// ```
+pub struct CsvField {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for CsvField {
+    type Error = CsvFieldError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if value.as_ref().contains(requires_quoting) {
+            Err(Self::Error::new())
+        } else {
+            Ok(())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("unquoted csv field")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::csv_field")
+    }
+}
+
+/// Represents errors that occur when the string is not a properly escaped CSV field.
+#[derive(Debug, Error, Default)]
+#[error("expected string to be properly escaped csv field")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::quotable_csv_field),
+        help("make sure the field is quoted if needed, with quotes inside doubled")
+    )
+)]
+pub struct QuotableCsvFieldError;
+
+impl QuotableCsvFieldError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the string is a properly escaped CSV field.
+///
+/// The field is either unquoted (see [`CsvField`]), or is enclosed in quotes,
+/// with every quote inside doubled (as per RFC 4180).
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::QuotableCsvField};
+///
+/// assert!(QuotableCsvField::is_satisfied("plain"));
+/// assert!(QuotableCsvField::is_satisfied("\"a,b\""));
+/// assert!(QuotableCsvField::is_satisfied("\"with\"\"quote\""));
+///
+/// assert!(!QuotableCsvField::is_satisfied("a,b"));
+/// assert!(!QuotableCsvField::is_satisfied("\"with\"quote\""));
+/// ```
+pub struct QuotableCsvField {
+    private: PhantomData<()>,
+}
+
+impl QuotableCsvField {
+    fn is_quotable_csv_field(string: &str) -> bool {
+        let Some(quoted) = string
+            .strip_prefix('"')
+            .and_then(|string| string.strip_suffix('"'))
+        else {
+            return !string.contains(requires_quoting);
+        };
+
+        // every quote inside must be doubled, meaning splitting on doubled quotes
+        // should leave no quotes behind
+        !quoted.split("\"\"").any(|part| part.contains('"'))
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for QuotableCsvField {
+    type Error = QuotableCsvFieldError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if Self::is_quotable_csv_field(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("properly escaped csv field")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::quotable_csv_field")
+    }
+}
+
+/// Represents errors that occur when the string is not a valid language tag.
+#[derive(Debug, Error, Default)]
+#[error("expected valid language tag")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::language_tag),
+        help("make sure the string is a language tag, like `en-US`")
+    )
+)]
+pub struct LanguageTagError;
+
+impl LanguageTagError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the string is a valid language tag, as per the pragmatic subset of BCP 47.
+///
+/// The accepted format consists of the following subtags, separated by `-`:
+///
+/// - primary language: 2 to 3 ASCII letters;
+/// - optional script: 4 ASCII letters;
+/// - optional region: 2 ASCII letters or 3 ASCII digits;
+/// - any number of variants: 5 to 8 ASCII alphanumeric characters,
+///   or 4 ASCII alphanumeric characters starting with a digit.
+///
+/// Extensions and private use subtags are not supported.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::LanguageTag};
+///
+/// assert!(LanguageTag::is_satisfied("en"));
+/// assert!(LanguageTag::is_satisfied("en-US"));
+/// assert!(LanguageTag::is_satisfied("zh-Hans-CN"));
+///
+/// assert!(!LanguageTag::is_satisfied("english"));
+/// ```
+pub struct LanguageTag {
+    private: PhantomData<()>,
+}
+
+impl LanguageTag {
+    fn is_alphabetic(subtag: &str, lengths: RangeInclusive<usize>) -> bool {
+        lengths.contains(&subtag.len()) && subtag.bytes().all(|byte| byte.is_ascii_alphabetic())
+    }
+
+    fn is_script(subtag: &str) -> bool {
+        Self::is_alphabetic(subtag, 4..=4)
+    }
+
+    fn is_region(subtag: &str) -> bool {
+        Self::is_alphabetic(subtag, 2..=2) || (subtag.len() == 3 && is_digits(subtag))
+    }
+
+    fn is_variant(subtag: &str) -> bool {
+        let alphanumeric = subtag.bytes().all(|byte| byte.is_ascii_alphanumeric());
+
+        match subtag.len() {
+            5..=8 => alphanumeric,
+            4 => alphanumeric && subtag.starts_with(|character: char| character.is_ascii_digit()),
+            _ => false,
+        }
+    }
+
+    fn is_language_tag(string: &str) -> bool {
+        let mut subtags = string.split('-').peekable();
+
+        if !subtags
+            .next()
+            .is_some_and(|language| Self::is_alphabetic(language, 2..=3))
+        {
+            return false;
+        }
+
+        subtags.next_if(|subtag| Self::is_script(subtag));
+        subtags.next_if(|subtag| Self::is_region(subtag));
+
+        subtags.all(Self::is_variant)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for LanguageTag {
+    type Error = LanguageTagError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if Self::is_language_tag(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("language tag")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::language_tag")
+    }
+}
+
+/// The separator of file extension parts.
+const DOT: char = '.';
+
+/// The separator of extensions in sets.
+const COMMA: char = ',';
+
+/// Checks whether the given string is non-empty and consists of ASCII alphanumeric characters.
+fn is_alphanumeric(string: &str) -> bool {
+    !string.is_empty() && string.bytes().all(|byte| byte.is_ascii_alphanumeric())
+}
+
+/// Checks whether the given string is valid file extension without the leading dot.
+fn is_file_extension(string: &str) -> bool {
+    string.split(DOT).all(is_alphanumeric)
+}
+
+/// Represents errors that occur when the string is not a valid file extension.
+#[derive(Debug, Error, Default)]
+#[error("expected valid file extension")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::file_extension),
+        help("make sure the string consists of alphanumeric parts separated by dots")
+    )
+)]
+pub struct FileExtensionError;
+
+impl FileExtensionError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the string is a valid file extension, without the leading dot.
+///
+/// Valid extensions consist of one or more non-empty ASCII alphanumeric parts,
+/// separated by dots (for instance, `rs` or `tar.gz`).
+///
+/// See [`DottedFileExtension`] for extensions with the leading dot.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::FileExtension};
+///
+/// assert!(FileExtension::is_satisfied("rs"));
+/// assert!(FileExtension::is_satisfied("tar.gz"));
+///
+/// assert!(!FileExtension::is_satisfied(".rs"));
+/// assert!(!FileExtension::is_satisfied(""));
+/// ```
+pub struct FileExtension {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for FileExtension {
+    type Error = FileExtensionError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if is_file_extension(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("file extension")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::file_extension")
+    }
+}
+
+/// Represents errors that occur when the string is not a valid file extension
+/// with the leading dot.
+#[derive(Debug, Error, Default)]
+#[error("expected valid file extension with leading dot")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::dotted_file_extension),
+        help("make sure the string consists of the dot and alphanumeric parts separated by dots")
+    )
+)]
+pub struct DottedFileExtensionError;
+
+impl DottedFileExtensionError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the string is a valid file extension, with the leading dot.
+///
+/// See [`FileExtension`] for more information.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::DottedFileExtension};
+///
+/// assert!(DottedFileExtension::is_satisfied(".rs"));
+/// assert!(DottedFileExtension::is_satisfied(".tar.gz"));
+///
+/// assert!(!DottedFileExtension::is_satisfied("rs"));
+/// assert!(!DottedFileExtension::is_satisfied("."));
+/// ```
+pub struct DottedFileExtension {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for DottedFileExtension {
+    type Error = DottedFileExtensionError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if value
+            .as_ref()
+            .strip_prefix(DOT)
+            .is_some_and(is_file_extension)
+        {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("file extension with leading dot")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::dotted_file_extension")
+    }
+}
+
+/// Represents errors that occur when the string does not have any of the expected [`extensions`].
+///
+/// [`extensions`]: Self::extensions
+#[derive(Debug, Error)]
+#[error("expected string to have one of `{extensions}` extensions")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::has_one_of_extensions),
+        help("make sure the string ends with one of `{extensions}` extensions")
+    )
+)]
+pub struct HasOneOfExtensionsError {
+    /// The expected extensions, separated by commas.
+    pub extensions: StaticStr,
+}
+
+impl HasOneOfExtensionsError {
+    /// Constructs [`Self`].
+    pub const fn new(extensions: StaticStr) -> Self {
+        Self { extensions }
+    }
+}
+
+/// Checks if the string (for instance, the file name) has one of the extensions in `S`.
+///
+/// The set `S` contains extensions without leading dots, separated by commas.
+/// The string must consist of the non-empty name followed by the dot and the extension.
+/// Extensions are compared case-sensitively.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::HasOneOfExtensions, type_str};
+///
+/// type_str!(Archives = "zip,tar.gz");
+///
+/// type Archive = HasOneOfExtensions<Archives>;
+///
+/// assert!(Archive::is_satisfied("release.zip"));
+/// assert!(Archive::is_satisfied("release.tar.gz"));
+///
+/// assert!(!Archive::is_satisfied("release.gz"));
+/// assert!(!Archive::is_satisfied(".zip"));
+/// ```
+pub struct HasOneOfExtensions<S: TypeStr + ?Sized> {
+    extensions: PhantomData<S>,
+}
+
+impl<S: TypeStr + ?Sized> HasOneOfExtensions<S> {
+    /// Returns the expected extensions, separated by commas.
+    pub const fn extensions() -> StaticStr {
+        S::VALUE
+    }
+
+    fn has_extension(string: &str, extension: &str) -> bool {
+        string
+            .strip_suffix(extension)
+            .and_then(|name| name.strip_suffix(DOT))
+            .is_some_and(|name| !name.is_empty())
+    }
+
+    fn has_one_of_extensions(string: &str) -> bool {
+        Self::extensions()
+            .split(COMMA)
+            .any(|extension| Self::has_extension(string, extension))
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, S: TypeStr + ?Sized> Predicate<T> for HasOneOfExtensions<S> {
+    type Error = HasOneOfExtensionsError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if Self::has_one_of_extensions(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new(Self::extensions()))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string with one of `{extensions}` extensions",
+            extensions = Self::extensions()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::has_one_of_extensions")
+    }
+}
+
+/// Checks whether the given character can be passed to shells without quoting.
+const fn is_shell_safe(character: char) -> bool {
+    character.is_ascii_alphanumeric() || matches!(character, '_' | '.' | '/' | '-')
+}
+
+/// Represents errors that occur when the string contains characters unsafe for shells.
+#[derive(Debug, Error)]
+#[error("received shell-unsafe character {character:?} at index {index}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::shell_safe),
+        help("make sure the string matches `[A-Za-z0-9_./-]*`")
+    )
+)]
+pub struct ShellSafeError {
+    /// The byte index of the unsafe character.
+    pub index: usize,
+    /// The unsafe character.
+    pub character: char,
+}
+
+impl ShellSafeError {
+    /// Constructs [`Self`].
+    pub const fn new(index: usize, character: char) -> Self {
+        Self { index, character }
+    }
+}
+
+/// Checks if the string can be passed to shells without quoting,
+/// that is, whether it consists of ASCII letters, digits, `_`, `.`, `/` and `-` only.
+///
+/// Note that empty strings are accepted, even though they vanish when passed unquoted;
+/// combine this predicate with [`NonEmpty`] to reject them.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::ShellSafe};
+///
+/// assert!(ShellSafe::is_satisfied("file-1.txt"));
+/// assert!(ShellSafe::is_satisfied("./path/to_file"));
+///
+/// let error = ShellSafe::check("a b").unwrap_err();
+///
+/// assert_eq!(error.character, ' ');
+/// assert_eq!(error.index, 1);
+///
+/// let error = ShellSafe::check("$(rm)").unwrap_err();
+///
+/// assert_eq!(error.character, '$');
+/// assert_eq!(error.index, 0);
+/// ```
+///
+/// [`NonEmpty`]: crate::empty::NonEmpty
+pub struct ShellSafe {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for ShellSafe {
+    type Error = ShellSafeError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        value
+            .as_ref()
+            .char_indices()
+            .find(|&(_, character)| !is_shell_safe(character))
+            .map_or(Ok(()), |(index, character)| {
+                Err(Self::Error::new(index, character))
+            })
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("shell-safe string")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::shell_safe")
+    }
+}
+
+/// Checks whether the given byte can be used in MIME type tokens.
+const fn is_mime_token_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric()
+        || matches!(
+            byte,
+            b'!' | b'#' | b'$' | b'&' | b'^' | b'_' | b'.' | b'+' | b'-'
+        )
+}
+
+/// Represents errors that occur when the string is not a valid MIME type.
+#[derive(Debug, Error, Default)]
+#[error("expected valid MIME type")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::mime_type),
+        help(
+            "make sure the string is `type/subtype`, optionally followed by `; name=value` parameters"
+        )
+    )
+)]
+pub struct MimeTypeError;
+
+impl MimeTypeError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the string is a valid MIME type.
+///
+/// This predicate accepts a pragmatic subset of the specification, namely, `type/subtype`
+/// optionally followed by `; name=value` parameters, where each of `type`, `subtype`
+/// and `name` is a non-empty token of ASCII letters, digits and `!#$&^_.+-` characters,
+/// and each `value` is either a token or a quoted string.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::MimeType};
+///
+/// assert!(MimeType::is_satisfied("text/plain"));
+/// assert!(MimeType::is_satisfied("application/vnd.api+json"));
+/// assert!(MimeType::is_satisfied("text/html; charset=utf-8"));
+/// assert!(MimeType::is_satisfied("multipart/form-data; boundary=\"nekit dev\""));
+///
+/// assert!(!MimeType::is_satisfied("notamimetype"));
+/// assert!(!MimeType::is_satisfied("text/"));
+/// assert!(!MimeType::is_satisfied("text/plain;"));
+/// ```
+pub struct MimeType {
+    private: PhantomData<()>,
+}
+
+impl MimeType {
+    fn is_token(string: &str) -> bool {
+        !string.is_empty() && string.bytes().all(is_mime_token_byte)
+    }
+
+    fn is_quoted(string: &str) -> bool {
+        string
+            .strip_prefix('"')
+            .and_then(|string| string.strip_suffix('"'))
+            .is_some_and(|inner| !inner.contains(['"', '\\']))
+    }
+
+    fn is_parameter(string: &str) -> bool {
+        string.trim().split_once('=').is_some_and(|(name, value)| {
+            Self::is_token(name) && (Self::is_token(value) || Self::is_quoted(value))
+        })
+    }
+
+    fn is_mime_type(string: &str) -> bool {
+        let mut parts = string.split(';');
+
+        let essence = parts.next().unwrap_or_default().trim_end();
+
+        essence
+            .split_once('/')
+            .is_some_and(|(kind, subtype)| Self::is_token(kind) && Self::is_token(subtype))
+            && parts.all(Self::is_parameter)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for MimeType {
+    type Error = MimeTypeError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if Self::is_mime_type(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("MIME type")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::mime_type")
+    }
+}
+
+/// Represents errors that occur when the string has unexpected number of segments.
+#[derive(Debug, Error)]
+#[error("expected {expected} segments separated by {separator:?}, received {received}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::segment_count),
+        help("make sure the string has {expected} segments separated by {separator:?}")
+    )
+)]
+pub struct SegmentCountError {
+    /// The separator (the `S`).
+    pub separator: char,
+    /// The expected number of segments (the `N`).
+    pub expected: usize,
+    /// The received number of segments.
+    pub received: usize,
+}
+
+impl SegmentCountError {
+    /// Constructs [`Self`].
+    pub const fn new(separator: char, expected: usize, received: usize) -> Self {
+        Self {
+            separator,
+            expected,
+            received,
+        }
+    }
+}
+
+/// Checks if the string has exactly `N` segments separated by `S`.
+///
+/// Segments are counted as per [`str::split`], meaning that empty segments are counted too;
+/// in particular, trailing separators produce trailing empty segments,
+/// and empty strings consist of one empty segment.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::SegmentCount};
+///
+/// type Version = SegmentCount<'.', 3>;
+///
+/// assert!(Version::is_satisfied("1.2.3"));
+///
+/// let error = Version::check("1.2").unwrap_err();
+///
+/// assert_eq!(error.received, 2);
+///
+/// let error = Version::check("1.2.3.").unwrap_err();
+///
+/// assert_eq!(error.received, 4);
+/// ```
+pub struct SegmentCount<const S: char, const N: usize> {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized, const S: char, const N: usize> Predicate<T> for SegmentCount<S, N> {
+    type Error = SegmentCountError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let received = value.as_ref().split(S).count();
+
+        if received == N {
+            Ok(())
+        } else {
+            Err(Self::Error::new(S, N, received))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "string with {N} segments separated by {S:?}")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::segment_count<{S:?}, {N}>")
+    }
+}
+
+/// Represents errors that occur when the string is not a valid hex color.
+#[derive(Debug, Error, Default)]
+#[error("expected valid hex color")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::hex_color),
+        help("make sure the string is `#RGB`, `#RRGGBB` or `#RRGGBBAA`")
+    )
+)]
+pub struct HexColorError;
+
+impl HexColorError {
+    /// Constructs [`Self`].
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+/// Checks if the string is a valid hex color, that is, `#RGB`, `#RRGGBB` or `#RRGGBBAA`.
+///
+/// Hexadecimal digits are case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::HexColor};
+///
+/// assert!(HexColor::is_satisfied("#fff"));
+/// assert!(HexColor::is_satisfied("#AABBCC"));
+/// assert!(HexColor::is_satisfied("#AaBbCc80"));
+///
+/// assert!(!HexColor::is_satisfied("#12345"));
+/// assert!(!HexColor::is_satisfied("AABBCC"));
+/// ```
+pub struct HexColor {
+    private: PhantomData<()>,
+}
+
+impl HexColor {
+    fn is_hex_color(string: &str) -> bool {
+        string.strip_prefix('#').is_some_and(|digits| {
+            matches!(digits.len(), 3 | 6 | 8) && digits.bytes().all(|byte| byte.is_ascii_hexdigit())
+        })
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for HexColor {
+    type Error = HexColorError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        if Self::is_hex_color(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(Self::Error::new())
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("hex color")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::hex_color")
+    }
+}
+
+/// The separator of JSON Pointer reference tokens.
+pub const JSON_POINTER_SEPARATOR: char = '/';
+
+/// The escape character of JSON Pointer reference tokens.
+pub const JSON_POINTER_ESCAPE: char = '~';
+
+/// Represents errors that occur when the string is not a valid JSON Pointer.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+pub enum JsonPointerError {
+    /// The non-empty string does not start with the separator.
+    #[error("expected JSON pointer to start with `{JSON_POINTER_SEPARATOR}`")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::json_pointer::separator),
+            help("make sure the pointer is either empty or starts with `/`")
+        )
+    )]
+    Separator,
+    /// The escape character is not followed by either `0` or `1`.
+    #[error("received invalid escape in JSON pointer at offset {offset}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::json_pointer::escape),
+            help("make sure `~` is only used in `~0` and `~1` escapes")
+        )
+    )]
+    Escape {
+        /// The byte offset of the invalid escape.
+        offset: usize,
+    },
+}
+
+impl JsonPointerError {
+    /// Constructs [`Self::Separator`].
+    pub const fn separator() -> Self {
+        Self::Separator
+    }
+
+    /// Constructs [`Self::Escape`].
+    pub const fn escape(offset: usize) -> Self {
+        Self::Escape { offset }
+    }
+}
+
+/// Checks if the string is a valid JSON Pointer, as per [RFC 6901].
+///
+/// JSON pointers are either empty or sequences of `/` followed by reference tokens,
+/// where `~` must be escaped as `~0` and `/` must be escaped as `~1`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     str::{JsonPointer, formats::JsonPointerError},
+/// };
+///
+/// assert!(JsonPointer::is_satisfied(""));
+/// assert!(JsonPointer::is_satisfied("/foo/0"));
+/// assert!(JsonPointer::is_satisfied("/a~1b"));
+///
+/// let error = JsonPointer::check("foo").unwrap_err();
+///
+/// assert!(matches!(error, JsonPointerError::Separator));
+///
+/// let error = JsonPointer::check("/a~2b").unwrap_err();
+///
+/// assert!(matches!(error, JsonPointerError::Escape { offset: 2 }));
+/// ```
+///
+/// [RFC 6901]: https://www.rfc-editor.org/rfc/rfc6901
+pub struct JsonPointer {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for JsonPointer {
+    type Error = JsonPointerError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let string = value.as_ref();
+
+        if string.is_empty() {
+            return Ok(());
+        }
+
+        if !string.starts_with(JSON_POINTER_SEPARATOR) {
+            return Err(Self::Error::separator());
+        }
+
+        let bytes = string.as_bytes();
+
+        string
+            .match_indices(JSON_POINTER_ESCAPE)
+            .map(|(offset, _)| offset)
+            .find(|&offset| !matches!(bytes.get(offset + 1), Some(b'0' | b'1')))
+            .map_or(Ok(()), |offset| Err(Self::Error::escape(offset)))
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("JSON pointer")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::json_pointer")
+    }
+}
+
+/// The scheme of data URIs, including the colon.
+#[cfg(feature = "url")]
+pub const DATA_URI_SCHEME: StaticStr = "data:";
+
+/// The marker of base64-encoded data URIs, including the semicolon.
+#[cfg(feature = "url")]
+pub const DATA_URI_BASE64: StaticStr = ";base64";
+
+/// Checks whether the given byte is in the standard base64 alphabet, excluding padding.
+#[cfg(feature = "url")]
+const fn is_base64_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'/')
+}
+
+/// Checks whether the given string is valid padded base64, using the standard alphabet.
+#[cfg(feature = "url")]
+fn is_base64(string: &str) -> bool {
+    let data = string.trim_end_matches('=');
+
+    let padding = string.len() - data.len();
+
+    string.len().is_multiple_of(4) && padding <= 2 && data.bytes().all(is_base64_byte)
+}
+
+/// Represents errors that occur when the string is not a valid data URI.
+#[cfg(feature = "url")]
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+pub enum DataUriError {
+    /// The string does not start with the scheme.
+    #[error("expected data URI to start with `{DATA_URI_SCHEME}`")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::data_uri::scheme),
+            help("make sure the string starts with `data:`")
+        )
+    )]
+    Scheme,
+    /// The string does not contain the comma separating the header from the data.
+    #[error("expected comma separating data URI header from data")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::data_uri::separator),
+            help("make sure the data is preceded by `,`")
+        )
+    )]
+    Separator,
+    /// The media type is invalid.
+    #[error("received invalid media type in data URI")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::data_uri::media_type),
+            help("make sure the media type is valid")
+        )
+    )]
+    MediaType,
+    /// The data is marked as base64, but is not valid base64.
+    #[error("received invalid base64 data in data URI")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::data_uri::base64),
+            help("make sure the data is padded base64 using the standard alphabet")
+        )
+    )]
+    Base64,
+}
+
+#[cfg(feature = "url")]
+impl DataUriError {
+    /// Constructs [`Self::Scheme`].
+    pub const fn scheme() -> Self {
+        Self::Scheme
+    }
+
+    /// Constructs [`Self::Separator`].
+    pub const fn separator() -> Self {
+        Self::Separator
+    }
+
+    /// Constructs [`Self::MediaType`].
+    pub const fn media_type() -> Self {
+        Self::MediaType
+    }
+
+    /// Constructs [`Self::Base64`].
+    pub const fn base64() -> Self {
+        Self::Base64
+    }
+}
+
+/// Checks if the string is a valid data URI, as per [RFC 2397].
+///
+/// Data URIs are of the form `data:[<media type>][;base64],<data>`, where the media type
+/// is checked as per [`MimeType`], and can be omitted, optionally keeping its parameters.
+///
+/// If the data URI is marked as base64, the data is checked to be padded base64
+/// using the standard alphabet; otherwise, the data is not checked.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     str::{DataUri, formats::DataUriError},
+/// };
+///
+/// assert!(DataUri::is_satisfied(
+///     "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg=="
+/// ));
+/// assert!(DataUri::is_satisfied("data:,Hello%2C%20World%21"));
+/// assert!(DataUri::is_satisfied("data:;charset=utf-8,text"));
+///
+/// let error = DataUri::check("image/png;base64,AAAA").unwrap_err();
+///
+/// assert!(matches!(error, DataUriError::Scheme));
+///
+/// let error = DataUri::check("data:image/png;base64").unwrap_err();
+///
+/// assert!(matches!(error, DataUriError::Separator));
+///
+/// let error = DataUri::check("data:image;base64,AAAA").unwrap_err();
+///
+/// assert!(matches!(error, DataUriError::MediaType));
+///
+/// let error = DataUri::check("data:image/png;base64,AA*A").unwrap_err();
+///
+/// assert!(matches!(error, DataUriError::Base64));
+/// ```
+///
+/// [RFC 2397]: https://www.rfc-editor.org/rfc/rfc2397
+#[cfg(feature = "url")]
+pub struct DataUri {
+    private: PhantomData<()>,
+}
+
+#[cfg(feature = "url")]
+impl DataUri {
+    fn is_media_type(string: &str) -> bool {
+        string.is_empty()
+            || string.strip_prefix(';').map_or_else(
+                || MimeType::is_mime_type(string),
+                |parameters| parameters.split(';').all(MimeType::is_parameter),
+            )
+    }
+}
+
+#[cfg(feature = "url")]
+impl<T: AsRef<str> + ?Sized> Predicate<T> for DataUri {
+    type Error = DataUriError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let string = value
+            .as_ref()
+            .strip_prefix(DATA_URI_SCHEME)
+            .ok_or_else(Self::Error::scheme)?;
+
+        let (header, data) = string.split_once(',').ok_or_else(Self::Error::separator)?;
+
+        let (media_type, base64) = header
+            .strip_suffix(DATA_URI_BASE64)
+            .map_or((header, false), |media_type| (media_type, true));
+
+        if !Self::is_media_type(media_type) {
+            return Err(Self::Error::media_type());
+        }
+
+        if base64 && !is_base64(data) {
+            return Err(Self::Error::base64());
+        }
+
+        Ok(())
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("data URI")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::data_uri")
+    }
+}
+
+/// The characters that are not allowed in filenames on Windows.
+pub const FILENAME_FORBIDDEN: [char; 9] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+
+/// The names that are reserved on Windows, regardless of extensions.
+pub const FILENAME_RESERVED: [StaticStr; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Represents errors that occur when the string is not a safe filename.
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+pub enum SafeFilenameError {
+    /// The filename is empty.
+    #[error("received empty filename")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::safe_filename::empty),
+            help("make sure the filename is non-empty")
+        )
+    )]
+    Empty,
+    /// The filename contains forbidden or control characters.
+    #[error("received forbidden character {character:?} at index {index} in filename")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::safe_filename::character),
+            help("make sure the filename has no control characters and none of `<>:\"/\\|?*`")
+        )
+    )]
+    Character {
+        /// The byte index of the forbidden character.
+        index: usize,
+        /// The forbidden character.
+        character: char,
+    },
+    /// The filename is reserved.
+    #[error("received reserved filename `{name}`")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::safe_filename::reserved),
+            help("make sure the filename (without extensions) is not reserved")
+        )
+    )]
+    Reserved {
+        /// The reserved name.
+        name: StaticStr,
+    },
+    /// The filename ends with dots or spaces.
+    #[error("received filename ending with {character:?}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::safe_filename::trailing),
+            help("make sure the filename does not end with dots or spaces")
+        )
+    )]
+    Trailing {
+        /// The trailing character.
+        character: char,
+    },
+}
+
+impl SafeFilenameError {
+    /// Constructs [`Self::Empty`].
+    pub const fn empty() -> Self {
+        Self::Empty
+    }
+
+    /// Constructs [`Self::Character`].
+    pub const fn character(index: usize, character: char) -> Self {
+        Self::Character { index, character }
+    }
+
+    /// Constructs [`Self::Reserved`].
+    pub const fn reserved(name: StaticStr) -> Self {
+        Self::Reserved { name }
+    }
+
+    /// Constructs [`Self::Trailing`].
+    pub const fn trailing(character: char) -> Self {
+        Self::Trailing { character }
+    }
+}
+
+/// Checks if the string is a filename that is safe across platforms.
+///
+/// Safe filenames are non-empty, do not contain control characters or any of [`FILENAME_FORBIDDEN`],
+/// are not reserved (see [`FILENAME_RESERVED`], which is checked ignoring case and extensions),
+/// and do not end with dots or spaces.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     str::{SafeFilename, formats::SafeFilenameError},
+/// };
+///
+/// assert!(SafeFilename::is_satisfied("report.pdf"));
+///
+/// let error = SafeFilename::check("a/b.txt").unwrap_err();
+///
+/// assert!(matches!(error, SafeFilenameError::Character { index: 1, character: '/' }));
+///
+/// let error = SafeFilename::check("CON").unwrap_err();
+///
+/// assert!(matches!(error, SafeFilenameError::Reserved { name: "CON" }));
+///
+/// let error = SafeFilename::check("nul.txt").unwrap_err();
+///
+/// assert!(matches!(error, SafeFilenameError::Reserved { name: "NUL" }));
+///
+/// let error = SafeFilename::check("name .").unwrap_err();
+///
+/// assert!(matches!(error, SafeFilenameError::Trailing { character: '.' }));
+/// ```
+pub struct SafeFilename {
+    private: PhantomData<()>,
+}
+
+impl SafeFilename {
+    fn is_forbidden(character: char) -> bool {
+        character.is_control() || FILENAME_FORBIDDEN.contains(&character)
+    }
+
+    fn reserved(string: &str) -> Option<StaticStr> {
+        let stem = string.split('.').next().unwrap_or_default().trim_end();
+
+        FILENAME_RESERVED
+            .into_iter()
+            .find(|name| stem.eq_ignore_ascii_case(name))
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for SafeFilename {
+    type Error = SafeFilenameError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let string = value.as_ref();
+
+        let Some(last) = string.chars().next_back() else {
+            return Err(Self::Error::empty());
+        };
+
+        if let Some((index, character)) = string
+            .char_indices()
+            .find(|&(_, character)| Self::is_forbidden(character))
+        {
+            return Err(Self::Error::character(index, character));
+        }
+
+        if let Some(name) = Self::reserved(string) {
+            return Err(Self::Error::reserved(name));
+        }
+
+        if matches!(last, '.' | ' ') {
+            return Err(Self::Error::trailing(last));
+        }
+
+        Ok(())
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("safe filename")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::safe_filename")
+    }
+}
@@ -0,0 +1,295 @@
+//! Predicates based on lines of strings.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::core::{ErrorCore, Predicate};
+
+#[cfg(feature = "heapless")]
+use crate::errors::Errors;
+
+const CARRIAGE_RETURN: u8 = b'\r';
+const LINE_FEED: u8 = b'\n';
+
+/// Represents errors that occur when some line does not satisfy the predicate.
+#[derive(Debug)]
+pub struct EachLineError<E> {
+    /// The number of the failing line, starting from `1`.
+    pub line: usize,
+    /// The error produced by the predicate.
+    pub error: E,
+}
+
+impl<E> EachLineError<E> {
+    /// Constructs [`Self`].
+    pub const fn new(line: usize, error: E) -> Self {
+        Self { line, error }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for EachLineError<E> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "error on line {line}: {error}",
+            line = self.line,
+            error = self.error
+        )
+    }
+}
+
+impl<E: ErrorCore + 'static> ErrorCore for EachLineError<E> {
+    fn source(&self) -> Option<&(dyn ErrorCore + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(feature = "diagnostics")]
+impl<E: Diagnostic + 'static> Diagnostic for EachLineError<E> {
+    fn code(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("str::each_line"))
+    }
+
+    fn help(&self) -> Option<Box<dyn fmt::Display + '_>> {
+        Some(Box::new("make sure each line satisfies the predicate"))
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        Some(&self.error)
+    }
+}
+
+/// Checks if each line of the string satisfies the predicate `P`.
+///
+/// Lines are split as per [`str::lines`], meaning that they end with either `\n` or `\r\n`,
+/// and the final line ending is optional.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, empty::NonEmpty, str::EachLine};
+///
+/// assert!(EachLine::<NonEmpty>::is_satisfied("one\ntwo\nthree\n"));
+///
+/// let error = EachLine::<NonEmpty>::check("one\n\nthree").unwrap_err();
+///
+/// assert_eq!(error.line, 2);
+/// ```
+pub struct EachLine<P: ?Sized> {
+    predicate: PhantomData<P>,
+}
+
+impl<T: AsRef<str> + ?Sized, P: Predicate<str> + ?Sized> Predicate<T> for EachLine<P> {
+    type Error = EachLineError<P::Error>;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        value
+            .as_ref()
+            .lines()
+            .zip(1..)
+            .try_for_each(|(line, number)| {
+                P::check(line).map_err(|error| Self::Error::new(number, error))
+            })
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "string where each line is ({})", P::expected())
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::each_line<{}>", P::expected_code())
+    }
+}
+
+/// Checks if each line of the string satisfies the predicate `P`,
+/// collecting errors of up to `N` failing lines.
+///
+/// Unlike [`EachLine`], this predicate does not stop on the first failing line.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, empty::NonEmpty, str::EveryLine};
+///
+/// let errors = EveryLine::<NonEmpty, 2>::check("\none\n\n\ntwo").unwrap_err();
+///
+/// assert_eq!(errors.total(), 3);
+/// assert_eq!(errors.skipped(), 1);
+///
+/// let lines: Vec<_> = errors.iter().map(|error| error.line).collect();
+///
+/// assert_eq!(lines, [1, 3]);
+/// ```
+#[cfg(feature = "heapless")]
+pub struct EveryLine<P: ?Sized, const N: usize> {
+    predicate: PhantomData<P>,
+}
+
+#[cfg(feature = "heapless")]
+impl<T: AsRef<str> + ?Sized, P: Predicate<str> + ?Sized, const N: usize> Predicate<T>
+    for EveryLine<P, N>
+{
+    type Error = Errors<EachLineError<P::Error>, N>;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let mut errors = Self::Error::new();
+
+        for (line, number) in value.as_ref().lines().zip(1..) {
+            if let Err(error) = P::check(line) {
+                errors.push(EachLineError::new(number, error));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "string where every line is ({})", P::expected())
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::every_line<{}, {N}>", P::expected_code())
+    }
+}
+
+/// Represents errors that occur when the string contains carriage returns.
+#[derive(Debug, Error)]
+#[error("expected unix line endings, found carriage return at offset {offset}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::unix_line_endings),
+        help("make sure lines end with `\\n` and the string contains no `\\r`")
+    )
+)]
+pub struct UnixLineEndingsError {
+    /// The byte offset of the carriage return.
+    pub offset: usize,
+}
+
+impl UnixLineEndingsError {
+    /// Constructs [`Self`].
+    pub const fn new(offset: usize) -> Self {
+        Self { offset }
+    }
+}
+
+/// Checks if the string uses unix line endings, that is, contains no `\r`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::UnixLineEndings};
+///
+/// assert!(UnixLineEndings::is_satisfied("one\ntwo\n"));
+/// assert!(UnixLineEndings::is_satisfied("no lines"));
+///
+/// let error = UnixLineEndings::check("one\ntwo\r\n").unwrap_err();
+///
+/// assert_eq!(error.offset, 7);
+/// ```
+pub struct UnixLineEndings {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for UnixLineEndings {
+    type Error = UnixLineEndingsError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        value
+            .as_ref()
+            .bytes()
+            .position(|byte| byte == CARRIAGE_RETURN)
+            .map_or(Ok(()), |offset| Err(Self::Error::new(offset)))
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("string with unix line endings")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::unix_line_endings")
+    }
+}
+
+/// Represents errors that occur when the string contains lone `\n` or `\r`.
+#[derive(Debug, Error)]
+#[error("expected windows line endings, found lone line break at offset {offset}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::windows_line_endings),
+        help("make sure lines end with `\\r\\n`")
+    )
+)]
+pub struct WindowsLineEndingsError {
+    /// The byte offset of the lone `\n` or `\r`.
+    pub offset: usize,
+}
+
+impl WindowsLineEndingsError {
+    /// Constructs [`Self`].
+    pub const fn new(offset: usize) -> Self {
+        Self { offset }
+    }
+}
+
+/// Checks if the string uses windows line endings, that is,
+/// every `\n` is preceded by `\r` and every `\r` is followed by `\n`.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::WindowsLineEndings};
+///
+/// assert!(WindowsLineEndings::is_satisfied("one\r\ntwo\r\n"));
+/// assert!(WindowsLineEndings::is_satisfied("no lines"));
+///
+/// let error = WindowsLineEndings::check("one\r\ntwo\n").unwrap_err();
+///
+/// assert_eq!(error.offset, 8);
+///
+/// let error = WindowsLineEndings::check("one\rtwo").unwrap_err();
+///
+/// assert_eq!(error.offset, 3);
+/// ```
+pub struct WindowsLineEndings {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for WindowsLineEndings {
+    type Error = WindowsLineEndingsError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let bytes = value.as_ref().as_bytes();
+
+        let lone = bytes
+            .iter()
+            .enumerate()
+            .position(|(offset, &byte)| match byte {
+                CARRIAGE_RETURN => bytes.get(offset + 1) != Some(&LINE_FEED),
+                LINE_FEED => offset == 0 || bytes[offset - 1] != CARRIAGE_RETURN,
+                _ => false,
+            });
+
+        lone.map_or(Ok(()), |offset| Err(Self::Error::new(offset)))
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("string with windows line endings")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::windows_line_endings")
+    }
+}
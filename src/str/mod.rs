@@ -1,13 +1,60 @@
 //! Predicates based on strings.
 
 pub mod bytes;
+pub mod canonical;
 pub mod chars;
 pub mod core;
+pub mod count;
+#[cfg(feature = "cron")]
+pub mod cron;
+pub mod formats;
+pub mod lines;
+pub mod percent;
+#[cfg(feature = "unicode-script")]
+pub mod script;
+#[cfg(feature = "unicode-width")]
+pub mod width;
 
 pub use core::{
-    Ascii, Contains, ContainsChar, EndsWith, EndsWithChar, StartsWith, StartsWithChar, Trimmed,
-    TrimmedEnd, TrimmedStart,
+    Ascii, Contains, ContainsChar, ContainsIgnoreCase, EndsWith, EndsWithChar, EndsWithIgnoreCase,
+    EqualsIgnoreCase, NoControl, NoWhitespace, ParsableAs, RequiresAndForbids, StartsWith,
+    StartsWithChar, StartsWithIgnoreCase, Trimmed, TrimmedEnd, TrimmedStart, UniformAsciiCase,
 };
 
+pub use canonical::Canonical;
+pub use chars::{
+    AllChars, AnyChar, CodePointBudget, ForAllChars, MinEntropyBits, NormalizedPalindrome,
+    Palindrome, TotalCodePointsAtMost, UniqueChars,
+};
+pub use count::{
+    SubstringCount, SubstringCountAtLeast, SubstringCountAtMost, SubstringCountExactly,
+};
+
+pub use formats::{
+    CsvField, Decimal, DottedFileExtension, EnvVarName, FileExtension, HasOneOfExtensions,
+    HexColor, JsonPointer, LanguageTag, MimeType, QuotableCsvField, SafeFilename, SegmentCount,
+    ShellSafe,
+};
+pub use lines::{EachLine, UnixLineEndings, WindowsLineEndings};
+pub use percent::{MinimalPercentEncoding, PercentEncodedLengthAtMost};
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use core::Lowercase;
+
 #[cfg(feature = "regex")]
-pub use core::Matches;
+pub use core::{FullMatch, Matches, MatchesAny, MatchesWithGroups, ValidRegex};
+
+#[cfg(feature = "heapless")]
+pub use lines::EveryLine;
+
+#[cfg(feature = "cron")]
+pub use cron::Cron;
+
+#[cfg(feature = "unicode-width")]
+pub use width::DisplayWidthAtMost;
+
+#[cfg(feature = "url")]
+pub use formats::DataUri;
+
+#[cfg(feature = "url")]
+pub use percent::PercentEncodedUtf8;
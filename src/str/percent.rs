@@ -0,0 +1,369 @@
+//! Predicates based on percent-encoding of strings.
+
+use core::{fmt, marker::PhantomData, str};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::core::Predicate;
+
+/// The length of percent-encoded bytes (`%XX`).
+const ENCODED_LENGTH: usize = 3;
+
+/// Checks whether the given byte is unreserved, that is, is never percent-encoded.
+///
+/// Unreserved bytes are ASCII letters, digits, `-`, `.`, `_` and `~`, as per RFC 3986.
+const fn is_unreserved(byte: u8) -> bool {
+    matches!(byte, b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~')
+}
+
+/// Computes the length of the given string after percent-encoding, without allocating.
+///
+/// Each unreserved byte is kept as-is, and every other byte is encoded as `%XX`.
+fn encoded_length(string: &str) -> usize {
+    string
+        .bytes()
+        .map(|byte| {
+            if is_unreserved(byte) {
+                1
+            } else {
+                ENCODED_LENGTH
+            }
+        })
+        .sum()
+}
+
+/// Decodes the percent-encoded byte from the given hexadecimal digits.
+const fn decode(high: u8, low: u8) -> Option<u8> {
+    match (hex_digit(high), hex_digit(low)) {
+        (Some(high), Some(low)) => Some(high << 4 | low),
+        _ => None,
+    }
+}
+
+/// Returns the value of the given hexadecimal digit.
+const fn hex_digit(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        _ => None,
+    }
+}
+
+/// Represents errors that occur when the percent-encoded string exceeds [`limit`].
+///
+/// [`limit`]: Self::limit
+#[derive(Debug, Error)]
+#[error("received percent-encoded length {length} > {limit}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::percent_encoded_length),
+        help("make sure the percent-encoded string is at most {limit} bytes long")
+    )
+)]
+pub struct PercentEncodedLengthError {
+    /// The percent-encoded length.
+    pub length: usize,
+    /// The maximum length (the `N`).
+    pub limit: usize,
+}
+
+impl PercentEncodedLengthError {
+    /// Constructs [`Self`].
+    pub const fn new(length: usize, limit: usize) -> Self {
+        Self { length, limit }
+    }
+}
+
+/// Checks if the string is at most `N` bytes long after percent-encoding.
+///
+/// Unreserved bytes (ASCII letters, digits, `-`, `.`, `_` and `~`) are counted as-is,
+/// while every other byte is counted as `3` bytes (`%XX`).
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::PercentEncodedLengthAtMost};
+///
+/// assert!(PercentEncodedLengthAtMost::<8>::is_satisfied("nekit"));
+///
+/// // 4 bytes, 12 bytes when percent-encoded
+/// let error = PercentEncodedLengthAtMost::<8>::check("?&=/").unwrap_err();
+///
+/// assert_eq!(error.length, 12);
+/// ```
+pub struct PercentEncodedLengthAtMost<const N: usize> {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized, const N: usize> Predicate<T> for PercentEncodedLengthAtMost<N> {
+    type Error = PercentEncodedLengthError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let length = encoded_length(value.as_ref());
+
+        if length <= N {
+            Ok(())
+        } else {
+            Err(Self::Error::new(length, N))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string of at most {N} bytes when percent-encoded"
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::percent_encoded_length_at_most<{N}>")
+    }
+}
+
+/// Represents errors that occur when the string contains unnecessary percent-encodings.
+#[derive(Debug, Error)]
+#[error("received unnecessary percent-encoding of {character:?} at offset {offset}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::minimal_encoding),
+        help("make sure unreserved characters are not percent-encoded")
+    )
+)]
+pub struct MinimalPercentEncodingError {
+    /// The byte offset of the unnecessary percent-encoding.
+    pub offset: usize,
+    /// The unreserved character that was percent-encoded.
+    pub character: char,
+}
+
+impl MinimalPercentEncodingError {
+    /// Constructs [`Self`].
+    pub const fn new(offset: usize, character: char) -> Self {
+        Self { offset, character }
+    }
+}
+
+/// Checks if the string contains no unnecessary percent-encodings,
+/// that is, no `%XX` that decodes to an unreserved character.
+///
+/// Unreserved characters are ASCII letters, digits, `-`, `.`, `_` and `~`.
+/// Note that malformed percent-encodings are not checked by this predicate.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::MinimalPercentEncoding};
+///
+/// assert!(MinimalPercentEncoding::is_satisfied("%20"));
+/// assert!(MinimalPercentEncoding::is_satisfied("nekit"));
+///
+/// let error = MinimalPercentEncoding::check("nekit%2Edev%41").unwrap_err();
+///
+/// assert_eq!(error.offset, 5);
+/// assert_eq!(error.character, '.');
+///
+/// assert!(!MinimalPercentEncoding::is_satisfied("%41"));
+/// ```
+pub struct MinimalPercentEncoding {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized> Predicate<T> for MinimalPercentEncoding {
+    type Error = MinimalPercentEncodingError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let bytes = value.as_ref().as_bytes();
+
+        let unnecessary = bytes
+            .windows(ENCODED_LENGTH)
+            .enumerate()
+            .find_map(|(offset, window)| match *window {
+                [b'%', high, low] => decode(high, low)
+                    .filter(|&byte| is_unreserved(byte))
+                    .map(|byte| (offset, char::from(byte))),
+                _ => None,
+            });
+
+        unnecessary.map_or(Ok(()), |(offset, character)| {
+            Err(Self::Error::new(offset, character))
+        })
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("minimally percent-encoded string")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::minimal_encoding")
+    }
+}
+
+/// Returns the width of the UTF-8 sequence starting with the given byte,
+/// or [`None`] if the byte can not start any.
+#[cfg(feature = "url")]
+const fn utf8_width(byte: u8) -> Option<usize> {
+    match byte {
+        0x00..=0x7F => Some(1),
+        0xC2..=0xDF => Some(2),
+        0xE0..=0xEF => Some(3),
+        0xF0..=0xF4 => Some(4),
+        _ => None,
+    }
+}
+
+/// The maximum width of UTF-8 sequences.
+#[cfg(feature = "url")]
+const UTF8_MAX_WIDTH: usize = 4;
+
+/// Represents errors that occur when the string is not percent-encoded UTF-8.
+#[cfg(feature = "url")]
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+pub enum PercentEncodedUtf8Error {
+    /// The percent-encoding is malformed, that is, `%` is not followed by two hex digits.
+    #[error("received malformed percent-encoding at offset {offset}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::percent_encoded_utf8::malformed),
+            help("make sure `%` is followed by two hexadecimal digits")
+        )
+    )]
+    Malformed {
+        /// The byte offset of the malformed percent-encoding.
+        offset: usize,
+    },
+    /// The decoded bytes are not valid UTF-8.
+    #[error("received invalid UTF-8 after decoding at offset {offset}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(str::percent_encoded_utf8::utf8),
+            help("make sure the decoded bytes are valid UTF-8")
+        )
+    )]
+    Utf8 {
+        /// The byte offset of the start of the invalid sequence.
+        offset: usize,
+    },
+}
+
+#[cfg(feature = "url")]
+impl PercentEncodedUtf8Error {
+    /// Constructs [`Self::Malformed`].
+    pub const fn malformed(offset: usize) -> Self {
+        Self::Malformed { offset }
+    }
+
+    /// Constructs [`Self::Utf8`].
+    pub const fn utf8(offset: usize) -> Self {
+        Self::Utf8 { offset }
+    }
+}
+
+/// Checks if the string is well-formed percent-encoding of valid UTF-8.
+///
+/// Each `%` must be followed by two hexadecimal digits, and the decoded bytes
+/// (including the ones that are not percent-encoded) must be valid UTF-8.
+/// The string is validated without allocating.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     str::{PercentEncodedUtf8, percent::PercentEncodedUtf8Error},
+/// };
+///
+/// assert!(PercentEncodedUtf8::is_satisfied("%E2%9C%93"));
+/// assert!(PercentEncodedUtf8::is_satisfied("nekit%20dev"));
+///
+/// let error = PercentEncodedUtf8::check("%FF").unwrap_err();
+///
+/// assert!(matches!(error, PercentEncodedUtf8Error::Utf8 { offset: 0 }));
+///
+/// let error = PercentEncodedUtf8::check("%G0").unwrap_err();
+///
+/// assert!(matches!(error, PercentEncodedUtf8Error::Malformed { offset: 0 }));
+///
+/// let error = PercentEncodedUtf8::check("%E2%9C").unwrap_err();
+///
+/// assert!(matches!(error, PercentEncodedUtf8Error::Utf8 { offset: 0 }));
+/// ```
+#[cfg(feature = "url")]
+pub struct PercentEncodedUtf8 {
+    private: PhantomData<()>,
+}
+
+#[cfg(feature = "url")]
+impl<T: AsRef<str> + ?Sized> Predicate<T> for PercentEncodedUtf8 {
+    type Error = PercentEncodedUtf8Error;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let bytes = value.as_ref().as_bytes();
+
+        let mut buffer = [0; UTF8_MAX_WIDTH];
+
+        let mut start = 0;
+        let mut filled = 0;
+        let mut width = 0;
+
+        let mut index = 0;
+
+        while let Some(&byte) = bytes.get(index) {
+            let offset = index;
+
+            let decoded = if byte == b'%' {
+                index += ENCODED_LENGTH;
+
+                match bytes.get(offset + 1..index) {
+                    Some(&[high, low]) => decode(high, low),
+                    _ => None,
+                }
+                .ok_or_else(|| Self::Error::malformed(offset))?
+            } else {
+                index += 1;
+
+                byte
+            };
+
+            if filled == 0 {
+                start = offset;
+
+                width = utf8_width(decoded).ok_or_else(|| Self::Error::utf8(start))?;
+            }
+
+            buffer[filled] = decoded;
+
+            filled += 1;
+
+            if filled == width {
+                str::from_utf8(&buffer[..width]).map_err(|_| Self::Error::utf8(start))?;
+
+                filled = 0;
+            }
+        }
+
+        if filled == 0 {
+            Ok(())
+        } else {
+            Err(Self::Error::utf8(start))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("percent-encoded UTF-8 string")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("str::percent_encoded_utf8")
+    }
+}
@@ -0,0 +1,100 @@
+//! Predicates based on Unicode scripts of strings.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use crate::{
+    char::script::{self, Script},
+    core::Predicate,
+    static_str::StaticStr,
+    str::ForAllChars,
+};
+
+/// Represents errors that occur when some character of the string is not in the script.
+#[derive(Debug, Error)]
+#[error("received character {character:?} at index {index} not in `{script}` script")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::script),
+        help("make sure all characters are in `{script}` script")
+    )
+)]
+pub struct InScriptError {
+    /// The byte index of the failing character.
+    pub index: usize,
+    /// The failing character.
+    pub character: char,
+    /// The name of the script.
+    pub script: StaticStr,
+}
+
+impl InScriptError {
+    /// Constructs [`Self`].
+    pub const fn new(index: usize, character: char, script: StaticStr) -> Self {
+        Self {
+            index,
+            character,
+            script,
+        }
+    }
+}
+
+/// Checks whether all characters of the string are in the script `S`.
+///
+/// This is [`ForAllChars`] over [`script::InScript`], with errors tailored to scripts.
+/// Note that characters shared between scripts, like spaces and digits, are not
+/// in any script; combine this predicate with others to accept them.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{
+///     Predicate,
+///     char::script::{Cyrillic, Latin},
+///     str::script::InScript,
+/// };
+///
+/// assert!(InScript::<Latin>::is_satisfied("Bonjour"));
+/// assert!(InScript::<Cyrillic>::is_satisfied("Привет"));
+///
+/// let error = InScript::<Latin>::check("Helло").unwrap_err();
+///
+/// assert_eq!((error.index, error.character), (3, 'л'));
+/// ```
+pub struct InScript<S: Script + ?Sized> {
+    script: PhantomData<S>,
+}
+
+impl<S: Script + ?Sized> InScript<S> {
+    /// Returns the name of the script.
+    pub const fn script() -> StaticStr {
+        S::NAME
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, S: Script + ?Sized> Predicate<T> for InScript<S> {
+    type Error = InScriptError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        ForAllChars::<script::InScript<S>>::check(value)
+            .map_err(|error| Self::Error::new(error.index, error.character, Self::script()))
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "string in `{script}` script",
+            script = Self::script()
+        )
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::script<{script}>", script = Self::script())
+    }
+}
@@ -0,0 +1,83 @@
+//! Predicates based on display widths of strings.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+
+use thiserror::Error;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::core::Predicate;
+
+/// Represents errors that occur when the string is wider than some bound.
+#[derive(Debug, Error)]
+#[error("received string with display width {width}, expected at most {max}")]
+#[cfg_attr(
+    feature = "diagnostics",
+    derive(Diagnostic),
+    diagnostic(
+        code(str::display_width),
+        help("make sure the string fits in {max} columns")
+    )
+)]
+pub struct DisplayWidthAtMostError {
+    /// The display width of the string.
+    pub width: usize,
+    /// The maximum display width (the `N`).
+    pub max: usize,
+}
+
+impl DisplayWidthAtMostError {
+    /// Constructs [`Self`].
+    pub const fn new(width: usize, max: usize) -> Self {
+        Self { width, max }
+    }
+}
+
+/// Checks if the display width of the string is at most `N` columns.
+///
+/// The width is computed as per [`UnicodeWidthStr::width`], so that, for instance,
+/// wide CJK characters take two columns.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{Predicate, str::DisplayWidthAtMost};
+///
+/// assert!(DisplayWidthAtMost::<5>::is_satisfied("nekit"));
+///
+/// let wide = "你好世界";
+///
+/// assert_eq!(wide.chars().count(), 4);
+///
+/// let error = DisplayWidthAtMost::<5>::check(wide).unwrap_err();
+///
+/// assert_eq!(error.width, 8);
+/// ```
+pub struct DisplayWidthAtMost<const N: usize> {
+    private: PhantomData<()>,
+}
+
+impl<T: AsRef<str> + ?Sized, const N: usize> Predicate<T> for DisplayWidthAtMost<N> {
+    type Error = DisplayWidthAtMostError;
+
+    fn check(value: &T) -> Result<(), Self::Error> {
+        let width = value.as_ref().width();
+
+        if width <= N {
+            Ok(())
+        } else {
+            Err(Self::Error::new(width, N))
+        }
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "string with display width <= {N}")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "str::display_width<{N}>")
+    }
+}
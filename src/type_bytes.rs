@@ -0,0 +1,56 @@
+//! Type-level byte strings.
+
+/// Represents static byte strings.
+pub type StaticBytes = &'static [u8];
+
+/// Represents type-level byte strings.
+pub trait TypeBytes {
+    /// The bytes value.
+    const VALUE: StaticBytes;
+}
+
+#[doc(hidden)]
+pub mod import {
+    pub use core::marker::PhantomData;
+}
+
+/// Lifts static byte strings to type-level byte strings.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::type_bytes;
+///
+/// type_bytes!(Zip = b"PK\x03\x04");
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```
+/// use core::marker::PhantomData;
+///
+/// use refinement_types::{TypeBytes, type_bytes::StaticBytes};
+///
+/// struct Zip {
+///     private: PhantomData<()>,
+/// }
+///
+/// impl TypeBytes for Zip {
+///     const VALUE: StaticBytes = b"PK\x03\x04";
+/// }
+/// ```
+#[macro_export]
+macro_rules! type_bytes {
+    ($vis: vis $name: ident = $value: expr $(=> $doc: expr)?) => {
+        $(
+            #[doc = $doc]
+        )?
+        $vis struct $name {
+            private: $crate::type_bytes::import::PhantomData<()>,
+        }
+
+        impl $crate::type_bytes::TypeBytes for $name {
+            const VALUE: $crate::type_bytes::StaticBytes = $value;
+        }
+    };
+}
@@ -0,0 +1,53 @@
+//! Type-level floats.
+
+/// Represents type-level floats.
+pub trait TypeFloat {
+    /// The float value.
+    const VALUE: f64;
+}
+
+#[doc(hidden)]
+pub mod import {
+    pub use core::marker::PhantomData;
+}
+
+/// Lifts constant floats to type-level floats.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::type_float;
+///
+/// type_float!(Epsilon = 1e-9);
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```
+/// use core::marker::PhantomData;
+///
+/// use refinement_types::TypeFloat;
+///
+/// struct Epsilon {
+///     private: PhantomData<()>,
+/// }
+///
+/// impl TypeFloat for Epsilon {
+///     const VALUE: f64 = 1e-9;
+/// }
+/// ```
+#[macro_export]
+macro_rules! type_float {
+    ($vis: vis $name: ident = $value: expr $(=> $doc: expr)?) => {
+        $(
+            #[doc = $doc]
+        )?
+        $vis struct $name {
+            private: $crate::type_float::import::PhantomData<()>,
+        }
+
+        impl $crate::type_float::TypeFloat for $name {
+            const VALUE: f64 = $value;
+        }
+    };
+}
@@ -0,0 +1,53 @@
+//! Type-level integers.
+
+/// Represents type-level integers.
+pub trait TypeInt {
+    /// The integer value.
+    const VALUE: i128;
+}
+
+#[doc(hidden)]
+pub mod import {
+    pub use core::marker::PhantomData;
+}
+
+/// Lifts constant integers to type-level integers.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::type_int;
+///
+/// type_int!(MaxUsers = 1000);
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```
+/// use core::marker::PhantomData;
+///
+/// use refinement_types::TypeInt;
+///
+/// struct MaxUsers {
+///     private: PhantomData<()>,
+/// }
+///
+/// impl TypeInt for MaxUsers {
+///     const VALUE: i128 = 1000;
+/// }
+/// ```
+#[macro_export]
+macro_rules! type_int {
+    ($vis: vis $name: ident = $value: expr $(=> $doc: expr)?) => {
+        $(
+            #[doc = $doc]
+        )?
+        $vis struct $name {
+            private: $crate::type_int::import::PhantomData<()>,
+        }
+
+        impl $crate::type_int::TypeInt for $name {
+            const VALUE: i128 = $value;
+        }
+    };
+}
@@ -1,12 +1,24 @@
 //! Type-level regular expressions.
 
-pub use regex::Regex;
+pub use regex::{Regex, RegexSet};
+
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, PoisonError, RwLock},
+};
 
 use crate::static_str::StaticStr;
 
 #[doc(hidden)]
 pub mod import {
     pub use std::{marker::PhantomData, sync::LazyLock};
+
+    use super::{INVALID, Regex};
+
+    /// Compiles the given pattern, anchored to match entire strings.
+    pub fn anchored(pattern: &str) -> Regex {
+        Regex::new(&format!("\\A(?:{pattern})\\z")).expect(INVALID)
+    }
 }
 
 /// Represents static regular expressions (as returned in [`get`] of [`TypeRegex`]).
@@ -18,6 +30,50 @@ pub type StaticRegex = &'static Regex;
 pub trait TypeRegex {
     /// Returns the compiled regular expression.
     fn get() -> StaticRegex;
+
+    /// Returns the compiled regular expression, anchored as `\A(?:...)\z`
+    /// to match entire strings.
+    ///
+    /// The default implementation compiles anchored expressions once per pattern,
+    /// caching them globally; [`type_regex!`] overrides it to cache them per type instead.
+    ///
+    /// [`type_regex!`]: crate::type_regex!
+    fn get_anchored() -> StaticRegex {
+        anchored(Self::get())
+    }
+}
+
+/// Returns the anchored version of the given regular expression, compiling it if needed.
+fn anchored(regex: StaticRegex) -> StaticRegex {
+    static ANCHORED: LazyLock<RwLock<HashMap<StaticStr, StaticRegex>>> =
+        LazyLock::new(RwLock::default);
+
+    let pattern = regex.as_str();
+
+    let cached = ANCHORED
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(pattern)
+        .copied();
+
+    cached.unwrap_or_else(|| {
+        ANCHORED
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(pattern)
+            .or_insert_with(|| Box::leak(Box::new(import::anchored(pattern))))
+    })
+}
+
+/// Represents static regular expression sets (as returned in [`get`] of [`TypeRegexSet`]).
+///
+/// [`get`]: TypeRegexSet::get
+pub type StaticRegexSet = &'static RegexSet;
+
+/// Represents type-level regular expression sets.
+pub trait TypeRegexSet {
+    /// Returns the compiled regular expression set.
+    fn get() -> StaticRegexSet;
 }
 
 /// The `invalid regex` literal.
@@ -28,7 +84,7 @@ pub const INVALID: StaticStr = "invalid regex";
 /// ```
 /// use refinement_types::type_regex;
 ///
-/// type_regex!(Integer = "^0|[1-9][0-9]*$");
+/// type_regex!(Integer = "^(0|[1-9][0-9]*)$");
 /// ```
 ///
 /// Is equivalent to:
@@ -45,11 +101,19 @@ pub const INVALID: StaticStr = "invalid regex";
 /// impl TypeRegex for Integer {
 ///     fn get() -> StaticRegex {
 ///         static REGEX: LazyLock<Regex> = LazyLock::new(|| {
-///             Regex::new("^0|[1-9][0-9]*$").expect("invalid regex")
+///             Regex::new("^(0|[1-9][0-9]*)$").expect("invalid regex")
 ///         });
 ///
 ///         LazyLock::force(&REGEX)
 ///     }
+///
+///     fn get_anchored() -> StaticRegex {
+///         static ANCHORED: LazyLock<Regex> = LazyLock::new(|| {
+///             Regex::new(r"\A(?:^(0|[1-9][0-9]*)$)\z").expect("invalid regex")
+///         });
+///
+///         LazyLock::force(&ANCHORED)
+///     }
 /// }
 /// ```
 #[macro_export]
@@ -72,6 +136,82 @@ macro_rules! type_regex {
 
                 LazyLock::force(&REGEX)
             }
+
+            fn get_anchored() -> $crate::type_regex::StaticRegex {
+                use $crate::type_regex::import::{LazyLock, anchored};
+
+                static ANCHORED: LazyLock<$crate::type_regex::Regex> =
+                    LazyLock::new(|| anchored($regex));
+
+                LazyLock::force(&ANCHORED)
+            }
+        }
+    };
+}
+
+/// Combines type-level regular expressions into type-level regular expression sets.
+///
+/// The set is compiled once, on first use.
+///
+/// ```
+/// use refinement_types::{type_regex, type_regex_set};
+///
+/// type_regex!(Integer = "^(0|[1-9][0-9]*)$");
+/// type_regex!(Hexadecimal = "^0x[0-9a-f]+$");
+///
+/// type_regex_set!(Number = [Integer, Hexadecimal]);
+/// ```
+///
+/// Is equivalent to:
+///
+/// ```
+/// use std::{marker::PhantomData, sync::LazyLock};
+///
+/// use refinement_types::{RegexSet, StaticRegexSet, TypeRegex, TypeRegexSet, type_regex};
+///
+/// type_regex!(Integer = "^(0|[1-9][0-9]*)$");
+/// type_regex!(Hexadecimal = "^0x[0-9a-f]+$");
+///
+/// struct Number {
+///     private: PhantomData<()>,
+/// }
+///
+/// impl TypeRegexSet for Number {
+///     fn get() -> StaticRegexSet {
+///         static REGEX_SET: LazyLock<RegexSet> = LazyLock::new(|| {
+///             RegexSet::new([Integer::get().as_str(), Hexadecimal::get().as_str()])
+///                 .expect("invalid regex")
+///         });
+///
+///         LazyLock::force(&REGEX_SET)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! type_regex_set {
+    ($vis: vis $name: ident = [$($regex: ty),+ $(,)?] $(=> $doc: expr)?) => {
+        $(
+            #[doc = $doc]
+        )?
+        $vis struct $name {
+            private: $crate::type_regex::import::PhantomData<()>,
+        }
+
+        impl $crate::type_regex::TypeRegexSet for $name {
+            fn get() -> $crate::type_regex::StaticRegexSet {
+                use $crate::type_regex::import::LazyLock;
+
+                static REGEX_SET: LazyLock<$crate::type_regex::RegexSet> = LazyLock::new(|| {
+                    $crate::type_regex::RegexSet::new([
+                        $(
+                            <$regex as $crate::type_regex::TypeRegex>::get().as_str()
+                        ),+
+                    ])
+                    .expect($crate::type_regex::INVALID)
+                });
+
+                LazyLock::force(&REGEX_SET)
+            }
         }
     };
 }
@@ -1,6 +1,8 @@
 //! Type-level strings.
 
-use crate::static_str::StaticStr;
+use core::marker::PhantomData;
+
+use crate::static_str::{self, StaticStr};
 
 /// Represents type-level strings.
 pub trait TypeStr {
@@ -53,3 +55,126 @@ macro_rules! type_str {
         }
     };
 }
+
+/// Declares type-level strings as equivalent contexts.
+///
+/// This macro implements [`ContextEq`] both ways, failing to compile
+/// if the values of the type-level strings differ.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{context_eq, type_str};
+///
+/// type_str!(Port = "port");
+/// type_str!(OtherPort = "port");
+///
+/// context_eq!(Port, OtherPort);
+/// ```
+///
+/// Differing values fail to compile:
+///
+/// ```compile_fail
+/// use refinement_types::{context_eq, type_str};
+///
+/// type_str!(Port = "port");
+/// type_str!(Host = "host");
+///
+/// context_eq!(Port, Host);
+/// ```
+///
+/// [`ContextEq`]: crate::core::ContextEq
+#[macro_export]
+macro_rules! context_eq {
+    ($left: ty, $right: ty) => {
+        $crate::type_str_eq!($left, $right);
+
+        impl $crate::core::ContextEq<$right> for $left {}
+
+        impl $crate::core::ContextEq<$left> for $right {}
+    };
+}
+
+/// Represents compile-time proofs that type-level strings `A` and `B` are equal.
+///
+/// Constructing values of this type fails to compile if the values of `A` and `B` differ.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{type_str, type_str::StrEq};
+///
+/// type_str!(Prefix = "nekit");
+/// type_str!(OtherPrefix = "nekit");
+///
+/// let _ = StrEq::<Prefix, OtherPrefix>::new();
+/// ```
+///
+/// Differing values fail to compile:
+///
+/// ```compile_fail
+/// use refinement_types::{type_str, type_str::StrEq};
+///
+/// type_str!(Prefix = "nekit");
+/// type_str!(OtherPrefix = "dev");
+///
+/// let _ = StrEq::<Prefix, OtherPrefix>::new();
+/// ```
+pub struct StrEq<A: TypeStr + ?Sized, B: TypeStr + ?Sized> {
+    left: PhantomData<A>,
+    right: PhantomData<B>,
+}
+
+impl<A: TypeStr + ?Sized, B: TypeStr + ?Sized> Default for StrEq<A, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: TypeStr + ?Sized, B: TypeStr + ?Sized> StrEq<A, B> {
+    /// Asserts that the values of `A` and `B` are equal at compile time.
+    pub const ASSERT: () = assert!(
+        static_str::equal(A::VALUE, B::VALUE),
+        "expected equal type-level strings"
+    );
+
+    /// Constructs [`Self`], failing to compile if the values of `A` and `B` differ.
+    pub const fn new() -> Self {
+        let () = Self::ASSERT;
+
+        Self {
+            left: PhantomData,
+            right: PhantomData,
+        }
+    }
+}
+
+/// Asserts that the values of the given type-level strings are equal at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use refinement_types::{type_str, type_str_eq};
+///
+/// type_str!(Prefix = "nekit");
+/// type_str!(OtherPrefix = "nekit");
+///
+/// type_str_eq!(Prefix, OtherPrefix);
+/// ```
+///
+/// Differing values fail to compile:
+///
+/// ```compile_fail
+/// use refinement_types::{type_str, type_str_eq};
+///
+/// type_str!(Prefix = "nekit");
+/// type_str!(OtherPrefix = "dev");
+///
+/// type_str_eq!(Prefix, OtherPrefix);
+/// ```
+#[macro_export]
+macro_rules! type_str_eq {
+    ($left: ty, $right: ty) => {
+        const _: () = $crate::type_str::StrEq::<$left, $right>::ASSERT;
+    };
+}
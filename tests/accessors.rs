@@ -0,0 +1,46 @@
+//! Checks that accessing refined values never re-runs predicates.
+
+#![cfg(not(feature = "unsafe-assert"))]
+
+use core::fmt;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use refinement_types::{Predicate, Refinement};
+
+static CHECKS: AtomicUsize = AtomicUsize::new(0);
+
+struct Counted;
+
+impl Predicate<u8> for Counted {
+    type Error = fmt::Error;
+
+    fn check(_value: &u8) -> Result<(), Self::Error> {
+        CHECKS.fetch_add(1, Ordering::SeqCst);
+
+        Ok(())
+    }
+
+    fn expect(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("anything")
+    }
+
+    fn expect_code(formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("counted")
+    }
+}
+
+#[test]
+fn accessors_do_not_check() {
+    let value = Refinement::<u8, Counted>::refine(13).unwrap();
+
+    assert_eq!(CHECKS.load(Ordering::SeqCst), 1);
+
+    for _ in 0..42 {
+        assert_eq!(*value.get(), 13);
+        assert_eq!(*value, 13);
+    }
+
+    assert_eq!(value.take(), 13);
+
+    assert_eq!(CHECKS.load(Ordering::SeqCst), 1);
+}
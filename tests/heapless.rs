@@ -0,0 +1,112 @@
+//! Checks that multiple errors can be collected without allocation.
+
+#![no_std]
+#![cfg(feature = "heapless")]
+
+use core::{
+    fmt::{self, Write},
+    str,
+};
+
+use refinement_types::{Predicate, empty::NonEmpty, int::u8, logic::All, str::EveryLine};
+
+type Checked = All<(u8::Greater<13>, u8::Less<42>, u8::Divisible<2>), 2>;
+
+/// Fixed-capacity buffer used to render errors without allocating.
+struct Buffer {
+    bytes: [u8; 256],
+    length: usize,
+}
+
+impl Buffer {
+    const fn new() -> Self {
+        Self {
+            bytes: [0; 256],
+            length: 0,
+        }
+    }
+
+    fn render<D: fmt::Display>(item: D) -> Self {
+        let mut buffer = Self::new();
+
+        write!(buffer, "{item}").unwrap();
+
+        buffer
+    }
+
+    fn as_str(&self) -> &str {
+        str::from_utf8(&self.bytes[..self.length]).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        let end = self.length + string.len();
+
+        self.bytes
+            .get_mut(self.length..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(string.as_bytes());
+
+        self.length = end;
+
+        Ok(())
+    }
+}
+
+#[test]
+fn all_collects_errors() {
+    assert!(Checked::is_satisfied(&34));
+
+    let errors = Checked::check(&7).unwrap_err();
+
+    assert_eq!(errors.total(), 2);
+    assert_eq!(errors.skipped(), 0);
+
+    let mut indices = errors.iter().map(|error| error.index);
+
+    assert_eq!(indices.next(), Some(0));
+    assert_eq!(indices.next(), Some(2));
+    assert_eq!(indices.next(), None);
+
+    assert_eq!(
+        Buffer::render(&errors.as_slice()[0]).as_str(),
+        "expected u8 > 13 (predicate at index 0)"
+    );
+
+    assert_eq!(
+        Buffer::render(&errors).as_str(),
+        "2 errors occurred: expected u8 > 13 (predicate at index 0); \
+        expected u8 % 2 == 0 (predicate at index 2)"
+    );
+}
+
+#[test]
+fn all_respects_capacity() {
+    type Single = All<(u8::Greater<13>, u8::Less<5>, u8::Divisible<2>), 1>;
+
+    let errors = Single::check(&7).unwrap_err();
+
+    assert_eq!(errors.total(), 3);
+    assert_eq!(errors.skipped(), 2);
+    assert_eq!(errors.as_slice()[0].index, 0);
+
+    assert_eq!(
+        Buffer::render(&errors).as_str(),
+        "3 errors occurred: expected u8 > 13 (predicate at index 0) (and 2 more)"
+    );
+}
+
+#[test]
+fn every_line_collects_errors() {
+    let errors = EveryLine::<NonEmpty, 2>::check("\none\n\n\ntwo").unwrap_err();
+
+    assert_eq!(errors.total(), 3);
+    assert_eq!(errors.skipped(), 1);
+
+    let mut lines = errors.iter().map(|error| error.line);
+
+    assert_eq!(lines.next(), Some(1));
+    assert_eq!(lines.next(), Some(3));
+    assert_eq!(lines.next(), None);
+}